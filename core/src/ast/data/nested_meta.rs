@@ -1,5 +1,5 @@
 use proc_macro2::{Delimiter, TokenStream, TokenTree};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::{
     ext::IdentExt,
     parse::{discouraged::Speculative, ParseStream, Parser},
@@ -9,6 +9,8 @@ use syn::{
     Token,
 };
 
+use crate::util::path_to_string;
+
 fn parse_meta_path<'a>(input: ParseStream<'a>) -> syn::Result<Path> {
     Ok(Path {
         leading_colon: input.parse()?,
@@ -106,14 +108,88 @@ pub enum NestedMeta {
 }
 
 impl NestedMeta {
+    /// Parse a comma-separated list of items, such as the contents of `#[my(...)]`.
+    ///
+    /// Dotted keys are shorthand for nesting: `http.port = 8080, http.tls` is read as
+    /// `http(port = 8080, tls)`, with all the items sharing a first segment grouped into one
+    /// list at the position of the first of them. Setting a key both directly and with dotted
+    /// keys in the same list is an error.
     pub fn parse_meta_list(tokens: TokenStream) -> syn::Result<Vec<Self>> {
-        syn::punctuated::Punctuated::<NestedMeta, Token![,]>::parse_terminated
-            .parse2(tokens)
-            .map(|punctuated| punctuated.into_iter().collect())
+        let items = Punctuated::<Item, Token![,]>::parse_terminated.parse2(tokens)?;
+
+        let mut nested: Vec<NestedMeta> = Vec::with_capacity(items.len());
+        // The key of each dotted group and the index of its list in `nested`.
+        let mut groups: Vec<(String, usize)> = vec![];
+        for item in items {
+            match item {
+                Item::Nested(item) => nested.push(item),
+                Item::Dotted(dotted) => {
+                    let key = path_to_string(&dotted.path);
+                    let group = groups.iter().find(|(k, _)| *k == key).map(|(_, i)| *i);
+                    match group.map(|i| &mut nested[i]) {
+                        Some(NestedMeta::Meta(Meta::List(list))) => {
+                            let rest = dotted.rest;
+                            list.tokens.extend(quote!(, #rest));
+                        }
+                        _ => {
+                            groups.push((key, nested.len()));
+                            nested.push(NestedMeta::Meta(Meta::List(dotted.into_list())));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (index, item) in nested.iter().enumerate() {
+            if let NestedMeta::Meta(meta) = item {
+                let key = path_to_string(meta.path());
+                if groups.iter().any(|(k, i)| *k == key && *i != index) {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        format!("`{}` cannot be set both directly and with dotted keys", key),
+                    ));
+                }
+            }
+        }
+
+        Ok(nested)
     }
 }
 
-impl syn::parse::Parse for NestedMeta {
+/// A dotted key such as `http.port = 8080`, which addresses `port` in the nested item `http`.
+struct DottedMeta {
+    path: Path,
+    dot: Token![.],
+    /// The item after the dot, which may itself use a dotted key.
+    rest: TokenStream,
+}
+
+impl DottedMeta {
+    /// Convert `http.port = 8080` to its nested form, `http(port = 8080)`.
+    fn into_list(self) -> MetaList {
+        MetaList {
+            path: self.path,
+            delimiter: MacroDelimiter::Paren(Paren(self.dot.span)),
+            tokens: self.rest,
+        }
+    }
+}
+
+impl ToTokens for DottedMeta {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens);
+        self.dot.to_tokens(tokens);
+        self.rest.to_tokens(tokens);
+    }
+}
+
+/// An item in a list, before dotted keys are grouped.
+enum Item {
+    Nested(NestedMeta),
+    Dotted(DottedMeta),
+}
+
+impl syn::parse::Parse for Item {
     fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
         // The difference from `syn::Meta` and `NestedMeta`:
         // 1. `syn::Meta` requires a path, named value, or meta list only.
@@ -131,19 +207,47 @@ impl syn::parse::Parse for NestedMeta {
         //     1. `outer(self(..))`
         //     2. `outer(super(..))`
         //     3. `outer(crate(..))`
+        // 3. `NestedMeta` allows dotted keys, which are shorthand for nesting.
+        //   1. `outer(inner.key = "value")` is `outer(inner(key = "value"))`
         if input.peek(syn::Lit) && !(input.peek(syn::LitBool) && input.peek2(syn::Token![=])) {
-            input.parse().map(Self::Lit)
+            input.parse().map(NestedMeta::Lit).map(Item::Nested)
         } else if input.peek(syn::Ident::peek_any)
             || input.peek(Token![::]) && input.peek3(syn::Ident::peek_any)
         {
             let path = parse_meta_path(input)?;
-            parse_meta_after_path(path, input).map(Self::Meta)
+            if input.peek(Token![.]) {
+                let dot = input.parse()?;
+                let rest = match input.parse()? {
+                    Item::Nested(NestedMeta::Meta(rest)) => rest.into_token_stream(),
+                    Item::Nested(NestedMeta::Lit(lit)) => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "expected identifier after `.`",
+                        ));
+                    }
+                    Item::Dotted(rest) => rest.into_token_stream(),
+                };
+                Ok(Item::Dotted(DottedMeta { path, dot, rest }))
+            } else {
+                parse_meta_after_path(path, input)
+                    .map(NestedMeta::Meta)
+                    .map(Item::Nested)
+            }
         } else {
             Err(input.error("expected identifier or literal"))
         }
     }
 }
 
+impl syn::parse::Parse for NestedMeta {
+    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        Ok(match input.parse()? {
+            Item::Nested(item) => item,
+            Item::Dotted(dotted) => NestedMeta::Meta(Meta::List(dotted.into_list())),
+        })
+    }
+}
+
 impl ToTokens for NestedMeta {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
@@ -155,6 +259,7 @@ impl ToTokens for NestedMeta {
 
 #[cfg(test)]
 mod tests {
+    use quote::quote;
     use syn::parse_quote;
 
     use super::*;
@@ -170,4 +275,49 @@ mod tests {
             NestedMeta::Meta(Meta::Path(parse_quote!(::prost::Message)))
         );
     }
+
+    fn list(tokens: TokenStream) -> Vec<String> {
+        NestedMeta::parse_meta_list(tokens)
+            .unwrap()
+            .iter()
+            .map(|item| item.to_token_stream().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn dotted_keys_are_grouped() {
+        assert_eq!(
+            list(quote!(http.port = 8080, name = "x", http.tls)),
+            vec!["http (port = 8080 , tls)", "name = \"x\""]
+        );
+    }
+
+    #[test]
+    fn dotted_keys_nest() {
+        let items = list(quote!(a.b.c = 1, a.b.d = 2));
+        assert_eq!(items, vec!["a (b . c = 1 , b . d = 2)"]);
+
+        let inner = NestedMeta::parse_meta_list(quote!(b.c = 1, b.d = 2)).unwrap();
+        assert_eq!(inner.len(), 1);
+    }
+
+    #[test]
+    fn dotted_key_alone() {
+        let input: NestedMeta = parse_quote!(http.port = 8080);
+        assert_eq!(input, NestedMeta::Meta(parse_quote!(http(port = 8080))));
+    }
+
+    #[test]
+    fn dotted_and_direct_conflict() {
+        let err = NestedMeta::parse_meta_list(quote!(http(port = 1), http.tls)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`http` cannot be set both directly and with dotted keys"
+        );
+    }
+
+    #[test]
+    fn dotted_literal_rejected() {
+        assert!(NestedMeta::parse_meta_list(quote!(http."x")).is_err());
+    }
 }
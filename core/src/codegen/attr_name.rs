@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+
+use crate::util::Override;
+
+/// The name of a field or variant in the input.
+///
+/// The name is usually known when deriving. A container with `rename_all = path::to::fn`
+/// instead has names which the generated code computes by calling that function, so this
+/// generates an expression rather than a literal.
+#[derive(Debug, Clone)]
+pub struct AttrName<'a> {
+    /// The name, or the argument passed to `rename_with` if that is set.
+    name: Cow<'a, str>,
+    /// A `fn(&str) -> String` which computes the name from `name`.
+    rename_with: Option<&'a syn::Expr>,
+}
+
+impl<'a> AttrName<'a> {
+    pub fn new(name: Cow<'a, str>, rename_with: Option<&'a syn::Expr>) -> Self {
+        Self { name, rename_with }
+    }
+
+    /// A name which is used exactly as written, such as an alias.
+    pub fn literal(name: &'a str) -> Self {
+        Self::new(Cow::Borrowed(name), None)
+    }
+
+    /// Get the name if it is known when deriving.
+    pub fn as_literal(&self) -> Option<&str> {
+        match self.rename_with {
+            Some(_) => None,
+            None => Some(&self.name),
+        }
+    }
+
+    /// Get a copy of this name which borrows from `self`.
+    pub fn by_ref(&'a self) -> Self {
+        Self::new(Cow::Borrowed(&self.name), self.rename_with)
+    }
+}
+
+/// Generates an expression of type `&str`, which lives until the end of the enclosing statement.
+impl ToTokens for AttrName<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        match self.rename_with {
+            Some(rename_with) => tokens.extend(quote_spanned!(rename_with.span()=>
+                &*_darling::export::identity::<fn(&str) -> _darling::export::String>(#rename_with)(#name)
+            )),
+            None => name.to_tokens(tokens),
+        }
+    }
+}
+
+/// Generate an expression for a message which replaces each `{}` in `template` with the next of
+/// `names`. The message is a literal if all of the names are known when deriving.
+pub(in crate::codegen) fn format_names(template: &str, names: &[&AttrName<'_>]) -> TokenStream {
+    let literals = names
+        .iter()
+        .map(|name| name.as_literal())
+        .collect::<Option<Vec<_>>>();
+
+    match literals {
+        Some(literals) => {
+            let mut pieces = template.split("{}");
+            let mut message = pieces.next().unwrap_or_default().to_string();
+            for (piece, name) in pieces.zip(literals) {
+                message.push_str(name);
+                message.push_str(piece);
+            }
+
+            quote!(#message)
+        }
+        None => quote!(format!(#template, #(#names),*)),
+    }
+}
+
+/// Generate an expression for the warning message reported when the field or variant `name`,
+/// which is marked `#[darling(deprecated)]`, appears in the input. `noun` is the kind of item,
+/// such as `"field"` or `"variant"`.
+pub(in crate::codegen) fn deprecation_message(
+    noun: &str,
+    name: &AttrName<'_>,
+    note: &Override<String>,
+) -> TokenStream {
+    let template = format!("use of deprecated {} `{{}}`", noun);
+    match note {
+        Override::Inherit => format_names(&template, &[name]),
+        Override::Explicit(note) => format_names(
+            &format!("{}: {{}}", template),
+            &[name, &AttrName::literal(note)],
+        ),
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::Ident;
+
+use crate::ast::Fields;
+use crate::codegen::{format_names, Field};
+
+/// Mark every field that is named in a `conflicts_with`, `requires`, or `one_of` constraint
+/// so that its declaration and match arm record the span where the field was seen.
+pub fn track_constrained_spans(fields: &mut [Field<'_>], one_of: &[Vec<Ident>]) {
+    let mut tracked = BTreeSet::new();
+    for field in fields.iter() {
+        if !field.conflicts_with.is_empty() || !field.requires.is_empty() {
+            tracked.insert(field.ident.clone());
+            tracked.extend(field.conflicts_with.iter().cloned());
+            tracked.extend(field.requires.iter().cloned());
+        }
+    }
+
+    tracked.extend(one_of.iter().flatten().cloned());
+
+    for field in fields {
+        if tracked.contains(field.ident) {
+            field.track_span = true;
+        }
+    }
+}
+
+/// Mark every named field of a struct with a `validate` function, so that errors the function
+/// reports at a field's location can be pointed at the key which set that field.
+///
+/// Transparent structs read their field directly, so they have no keys to track.
+pub fn track_validated_spans(fields: &mut Fields<Field<'_>>, transparent: bool) {
+    if !fields.is_struct() || (transparent && fields.len() == 1) {
+        return;
+    }
+
+    for field in &mut fields.fields {
+        if field.as_name().is_some() {
+            field.track_span = true;
+        }
+    }
+}
+
+/// Name of the local variable holding the span where a field was first seen.
+pub(in crate::codegen) fn span_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_span", ident)
+}
+
+/// Generates the checks for `conflicts_with`, `requires`, and `one_of` constraints.
+///
+/// This must be placed after all input items have been read, and relies on the span
+/// variables declared for fields with `track_span` set.
+pub struct ConstraintChecks<'a> {
+    fields: &'a [Field<'a>],
+    one_of: &'a [Vec<Ident>],
+    /// An expression for the input being read, on which an unsatisfied `one_of` is spanned.
+    container: Option<TokenStream>,
+}
+
+impl<'a> ConstraintChecks<'a> {
+    pub fn new(
+        fields: &'a [Field<'a>],
+        one_of: &'a [Vec<Ident>],
+        container: Option<TokenStream>,
+    ) -> Self {
+        Self {
+            fields,
+            one_of,
+            container,
+        }
+    }
+
+    fn find(&self, ident: &Ident) -> &'a Field<'a> {
+        self.fields
+            .iter()
+            .find(|f| f.ident == ident)
+            .expect("Constraint targets were validated when parsing options")
+    }
+}
+
+impl ToTokens for ConstraintChecks<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // Declaring a conflict on both fields of a pair should only produce one set of errors.
+        let mut seen_conflicts = BTreeSet::new();
+
+        for field in self.fields {
+            let name = &field.name_in_attr;
+            let span = span_var(field.ident);
+
+            for other in field.conflicts_with {
+                let mut pair = [field.ident.to_string(), other.to_string()];
+                pair.sort();
+                if !seen_conflicts.insert(pair) {
+                    continue;
+                }
+
+                let other_name = &self.find(other).name_in_attr;
+                let other_span = span_var(other);
+                let msg = format_names(
+                    "`{}` cannot be used together with `{}`",
+                    &[name, other_name],
+                );
+                let other_msg = format_names(
+                    "`{}` cannot be used together with `{}`",
+                    &[other_name, name],
+                );
+
+                tokens.append_all(quote! {
+                    if let (_darling::export::Some(__span), _darling::export::Some(__other_span)) = (#span, #other_span) {
+                        __errors.push(_darling::Error::custom(#msg).with_span(&__span));
+                        __errors.push(_darling::Error::custom(#other_msg).with_span(&__other_span));
+                    }
+                });
+            }
+
+            for other in field.requires {
+                let other_name = &self.find(other).name_in_attr;
+                let other_span = span_var(other);
+                let msg = format_names("`{}` requires `{}` to also be set", &[name, other_name]);
+
+                tokens.append_all(quote! {
+                    if let (_darling::export::Some(__span), _darling::export::None) = (#span, #other_span) {
+                        __errors.push(_darling::Error::custom(#msg).with_span(&__span));
+                    }
+                });
+            }
+        }
+
+        for group in self.one_of {
+            let names = group
+                .iter()
+                .map(|ident| &self.find(ident).name_in_attr)
+                .collect::<Vec<_>>();
+            let list = vec!["`{}`"; names.len()].join(", ");
+            let spans = group.iter().map(span_var);
+            let missing_msg = format_names(&format!("Exactly one of {} must be set", list), &names);
+            let excess_msg = format_names(&format!("Only one of {} may be set", list), &names);
+            let container_span = self
+                .container
+                .as_ref()
+                .map(|container| quote!(.with_span(&#container)));
+
+            tokens.append_all(quote! {
+                {
+                    let __present = [#(#spans),*]
+                        .iter()
+                        .flatten()
+                        .copied()
+                        .collect::<_darling::export::Vec<_>>();
+
+                    match __present.len() {
+                        0 => __errors.push(_darling::Error::custom(#missing_msg)#container_span),
+                        1 => {}
+                        _ => {
+                            for __span in __present {
+                                __errors.push(_darling::Error::custom(#excess_msg).with_span(&__span));
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use syn::{spanned::Spanned, Ident};
+
+use crate::codegen::{DefaultExpression, Field};
+
+/// Generates the code which computes `default_from` fallbacks once every other field has been
+/// read and the container's fallback declared.
+///
+/// Fields are visited so that each computed default runs after the defaults it reads. A field
+/// read by a computed default has its own fallback applied first, so the computation sees the
+/// same value the field will have in the result.
+pub struct DefaultFromFields<'a> {
+    fields: &'a [Field<'a>],
+}
+
+impl<'a> DefaultFromFields<'a> {
+    pub fn new(fields: &'a [Field<'a>]) -> Self {
+        Self { fields }
+    }
+
+    fn find(&self, ident: &Ident) -> Option<&'a Field<'a>> {
+        self.fields.iter().find(|f| f.ident == ident)
+    }
+
+    /// Put `field` after the computed defaults it reads. Cycles were rejected when the
+    /// options were validated.
+    fn visit(&self, field: &'a Field<'a>, order: &mut Vec<&'a Field<'a>>) {
+        if order.iter().any(|f| f.ident == field.ident) {
+            return;
+        }
+
+        if let Some((dependencies, _)) = field.default_from {
+            for dependency in dependencies.iter().filter_map(|d| self.find(d)) {
+                if dependency.default_from.is_some() {
+                    self.visit(dependency, order);
+                }
+            }
+        }
+
+        order.push(field);
+    }
+}
+
+impl ToTokens for DefaultFromFields<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut order = vec![];
+        for field in self.fields.iter().filter(|f| f.default_from.is_some()) {
+            self.visit(field, &mut order);
+        }
+
+        let mut resolved: Vec<&Ident> = vec![];
+        for field in order {
+            let (dependencies, with) = field
+                .default_from
+                .expect("Only fields with `default_from` are ordered");
+            let dependencies = dependencies
+                .iter()
+                .filter_map(|d| self.find(d))
+                .collect::<Vec<_>>();
+
+            for dependency in &dependencies {
+                if dependency.default_from.is_some() || resolved.contains(&dependency.ident) {
+                    continue;
+                }
+
+                resolved.push(dependency.ident);
+                match &dependency.default_expression {
+                    // Moving out of the container's fallback here would conflict with the
+                    // field's initializer, so inherited values are borrowed instead.
+                    Some(DefaultExpression::Inherit(_)) | None => {}
+                    Some(expr) => {
+                        let ident = dependency.ident;
+                        tokens.append_all(quote_spanned!(expr.span()=>
+                            if #ident.1.is_none() {
+                                #ident.1 = _darling::export::Some(#expr);
+                            }
+                        ));
+                    }
+                }
+            }
+
+            let ident = field.ident;
+            let tys = dependencies.iter().map(|d| d.ty);
+            let args = dependencies.iter().map(|d| {
+                let ident = d.ident;
+                match &d.default_expression {
+                    Some(expr @ DefaultExpression::Inherit(_)) if d.default_from.is_none() => {
+                        quote!(#ident.1.as_ref().unwrap_or(&#expr))
+                    }
+                    _ => quote!(#ident.1.as_ref().expect("Fields read by `default_from` were already resolved")),
+                }
+            });
+            tokens.append_all(quote_spanned!(with.span()=>
+                if #ident.1.is_none() {
+                    #ident.1 = _darling::export::Some(
+                        _darling::export::identity::<fn(#(&#tys),*) -> _>(#with)(#(#args),*)
+                    );
+                }
+            ));
+        }
+    }
+}
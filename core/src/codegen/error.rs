@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
 
+use crate::codegen::AttrName;
+
 /// Declares the local variable into which errors will be accumulated.
 #[derive(Default)]
 pub struct ErrorDeclaration {
@@ -15,15 +17,31 @@ impl ToTokens for ErrorDeclaration {
     }
 }
 
+/// Declares the local list into which warnings will be collected.
+///
+/// Generated `FromMeta` impls receive this list as a parameter instead.
+#[derive(Default)]
+pub struct WarningsDeclaration {
+    __hidden: (),
+}
+
+impl ToTokens for WarningsDeclaration {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(quote! {
+            let __warnings: &mut _darling::export::Vec<_darling::error::Warning> = &mut _darling::export::Vec::new();
+        })
+    }
+}
+
 /// Returns early if attribute or body parsing has caused any errors.
 #[derive(Default)]
 pub struct ErrorCheck<'a> {
-    location: Option<&'a str>,
+    location: Option<&'a AttrName<'a>>,
     __hidden: (),
 }
 
 impl<'a> ErrorCheck<'a> {
-    pub fn with_location(location: &'a str) -> Self {
+    pub fn with_location(location: &'a AttrName<'a>) -> Self {
         ErrorCheck {
             location: Some(location),
             __hidden: (),
@@ -1,11 +1,16 @@
 use std::borrow::Cow;
 
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{spanned::Spanned, Ident, Type};
 
-use crate::codegen::{DefaultExpression, PostfixTransform};
+use crate::codegen::constraints::span_var;
+use crate::codegen::{
+    deprecation_message, format_names, names_pattern, AttrName, DefaultExpression, PostfixTransform,
+};
+use crate::options::{FieldValidator, OnDuplicate};
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
+use crate::util::Override;
 
 /// Properties needed to generate code for a field in all the contexts
 /// where one may appear.
@@ -13,7 +18,11 @@ use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
 pub struct Field<'a> {
     /// The name presented to the user of the library. This will appear
     /// in error messages and will be looked when parsing names.
-    pub name_in_attr: Cow<'a, str>,
+    pub name_in_attr: AttrName<'a>,
+
+    /// Additional names which will be accepted in place of `name_in_attr` when parsing.
+    /// These are treated as the same key for duplicate detection.
+    pub aliases: &'a [String],
 
     /// The name presented to the author of the library. This will appear
     /// in the setters or temporary variables which contain the values.
@@ -22,49 +31,247 @@ pub struct Field<'a> {
     /// The type of the field in the input.
     pub ty: &'a Type,
     pub default_expression: Option<DefaultExpression<'a>>,
+    /// A literal fallback from `default_value` which is read with the field type's
+    /// `FromMeta::from_expr` when the field is absent.
+    pub default_value: Option<&'a syn::Expr>,
+    /// The fields read by a computed fallback, and the callable which computes it from them.
+    pub default_from: Option<(&'a [Ident], &'a syn::Expr)>,
     /// An expression that will be wrapped in a call to [`core::convert::identity`] and
     /// then used for converting a provided value into the field value _before_ postfix
     /// transforms are called.
     pub with_callable: Cow<'a, syn::Expr>,
+    /// Whether `with_callable` was set by `#[darling(with = ...)]` rather than being the field
+    /// type's `FromMeta::from_meta`.
+    pub custom_with: bool,
+    /// An expression used instead of `with_callable` when the field appears as a bare word.
+    pub from_word: Option<&'a syn::Expr>,
+    /// An expression used instead of `with_callable` to read the value of `name = value`.
+    pub from_expr: Option<&'a syn::Expr>,
+    /// Checks applied to each value read for this field, after any postfix transform.
+    pub validators: &'a [FieldValidator],
     pub post_transform: Option<&'a PostfixTransform>,
     pub skip: bool,
     pub multiple: bool,
+    /// The fewest occurrences a `multiple` field accepts when it appears in the input.
+    pub min_items: Option<usize>,
+    /// The most occurrences a `multiple` field accepts.
+    pub max_items: Option<usize>,
+    /// The field of each item used as its key when a `multiple` field collects into a map.
+    pub key: Option<&'a Ident>,
+    /// If set, the field is deprecated and a warning with this note will be reported
+    /// whenever the field is present in the input.
+    pub deprecated: Option<&'a Override<String>>,
     /// If set, this field will be given all unclaimed meta items and will
     /// not be exposed as a standard named field.
     pub flatten: bool,
+    /// The prefix which keys given to this flattened field must have. It is removed before the
+    /// key is passed to the field's type.
+    pub flatten_prefix: &'a str,
+    /// The suffix which keys given to this flattened field must have, like `flatten_prefix`.
+    pub flatten_suffix: &'a str,
+    /// If set, the field's names match the input regardless of case, `_`, and `-`.
+    pub case_insensitive: bool,
+    /// If set, this field can be populated by a literal or bare path in the input,
+    /// matched by position rather than by name.
+    pub positional: bool,
+    /// If set, this field will be given every input item that no other field claimed,
+    /// including literals, and will not be exposed as a standard named field.
+    pub rest: bool,
+    /// If set, this field will be given the warnings produced while reading the input,
+    /// and will not be exposed as a standard named field.
+    pub warnings: bool,
+    /// How repeated occurrences of this field in the input are handled.
+    /// This has no effect on `multiple` fields, which accept every occurrence.
+    pub on_duplicate: OnDuplicate,
+    /// Sibling fields which must not be present in the input at the same time as this field.
+    pub conflicts_with: &'a [Ident],
+    /// Sibling fields which must be present in the input whenever this field is present.
+    pub requires: &'a [Ident],
+    /// If set, generated code records the span where this field was first seen.
+    /// This is needed for fields involved in cross-field constraints, and for the fields of a
+    /// container with a `validate` function.
+    pub track_span: bool,
 }
 
 impl<'a> Field<'a> {
     /// Get the name of the meta item that should be matched against input and should be used in diagnostics.
     ///
-    /// This will be `None` if the field is `skip` or `flatten`, as neither kind of field is addressable
-    /// by name from the input meta.
-    pub fn as_name(&'a self) -> Option<&'a str> {
-        if self.skip || self.flatten {
+    /// This will be `None` if the field is `skip`, `flatten`, `rest`, or `warnings`, as none of those
+    /// fields are addressable by name from the input meta.
+    pub fn as_name(&'a self) -> Option<&'a AttrName<'a>> {
+        if self.skip || self.flatten || self.rest || self.warnings {
             None
         } else {
             Some(&self.name_in_attr)
         }
     }
 
+    /// Get the primary name and all aliases of this field, in that order.
+    ///
+    /// This will be empty if the field is `skip`, `flatten`, `rest`, or `warnings`.
+    pub fn as_names(&'a self) -> impl Iterator<Item = AttrName<'a>> {
+        let aliases = if self.as_name().is_some() {
+            self.aliases
+        } else {
+            &[]
+        };
+
+        self.as_name()
+            .map(AttrName::by_ref)
+            .into_iter()
+            .chain(aliases.iter().map(|alias| AttrName::literal(alias)))
+    }
+
+    /// Generate an expression which converts the `syn::Meta` in the local `__inner` into
+    /// a `darling::Result` of the field's value, adding `location` to any error.
+    pub(in crate::codegen) fn as_extractor(&self, location: TokenStream) -> TokenStream {
+        let with_callable = &self.with_callable;
+        let post_transform = self.post_transform.as_ref();
+
+        // Give darling's generated code the span of the `with_callable` so that if the target
+        // type doesn't impl FromMeta, darling's immediate user gets a properly-spanned error.
+        //
+        // Within the generated code, add the span immediately on extraction failure, so that it's
+        // as specific as possible.
+        // The behavior of `with_span` makes this safe to do; if the child applied an
+        // even-more-specific span, our attempt here will not overwrite that and will only cost
+        // us one `if` check.
+        //
+        // Without a custom `with`, the field type's own `FromMeta` impl is given the warnings
+        // list so that warnings from nested items reach the caller.
+        let with = if self.custom_with {
+            quote_spanned!(with_callable.span()=>
+                _darling::export::identity::<fn(&_darling::export::syn::Meta) -> _darling::Result<_>>(#with_callable)(__inner)
+            )
+        } else {
+            quote_spanned!(with_callable.span()=>
+                _darling::FromMeta::from_meta_with_warnings(__inner, __warnings)
+            )
+        };
+
+        // Field-level `from_word` and `from_expr` take precedence over `with_callable` for the
+        // input shapes they handle.
+        let from_word = self.from_word.map(|from_word| {
+            quote_spanned!(from_word.span()=>
+                _darling::export::syn::Meta::Path(_) => _darling::export::identity::<fn() -> _darling::Result<_>>(#from_word)(),
+            )
+        });
+        let from_expr = self.from_expr.map(|from_expr| {
+            quote_spanned!(from_expr.span()=>
+                _darling::export::syn::Meta::NameValue(ref __nv) => _darling::export::identity::<fn(&_darling::export::syn::Expr) -> _darling::Result<_>>(#from_expr)(&__nv.value),
+            )
+        });
+        let read = if from_word.is_some() || from_expr.is_some() {
+            quote! {
+                (match *__inner {
+                    #from_word
+                    #from_expr
+                    _ => #with,
+                })
+            }
+        } else {
+            with
+        };
+
+        let validate = self.as_validation();
+
+        quote_spanned!(with_callable.span()=>
+        #read
+            #post_transform
+            #validate
+            .map_err(|e| e.with_span(&__inner).at(#location))
+        )
+    }
+
+    /// Generate the call which runs this field's `validate(...)` checks on the value in a
+    /// `darling::Result`. Failures are accumulated and spanned on the value when there is one.
+    fn as_validation(&self) -> Option<TokenStream> {
+        if self.validators.is_empty() {
+            return None;
+        }
+
+        let checks = self.validators.iter().map(|validator| match validator {
+            FieldValidator::Range { min, max, span } => {
+                let min = optional_bound(min.as_ref());
+                let max = optional_bound(max.as_ref());
+                quote_spanned!(*span=> _darling::util::validate::range(&__value, #min, #max))
+            }
+            FieldValidator::NonEmpty { span } => {
+                quote_spanned!(*span=> _darling::util::validate::non_empty(&__value))
+            }
+            FieldValidator::Len { bounds, span } => {
+                let min = optional_bound(bounds.min.as_ref());
+                let max = optional_bound(bounds.max.as_ref());
+                quote_spanned!(*span=> _darling::util::validate::len(&__value, #min, #max))
+            }
+            FieldValidator::OneOf { values, span } => {
+                quote_spanned!(*span=> _darling::util::validate::one_of(&__value, &[#(#values),*]))
+            }
+        });
+
+        Some(quote! {
+            .and_then(|__value| {
+                let mut __validation = _darling::Error::accumulator();
+                #(__validation.handle(#checks);)*
+                __validation.finish_with(__value).map_err(|__error| match *__inner {
+                    _darling::export::syn::Meta::NameValue(ref __nv) => __error.with_span(&__nv.value),
+                    _ => __error,
+                })
+            })
+        })
+    }
+
     pub fn as_declaration(&'a self) -> Declaration<'a> {
         Declaration(self)
     }
 
-    pub fn as_flatten_initializer(
-        &'a self,
-        parent_field_names: Vec<&'a str>,
-    ) -> FlattenInitializer<'a> {
-        FlattenInitializer {
-            field: self,
-            parent_field_names,
+    /// Whether this field is flattened with a prefix or suffix on its keys.
+    pub(in crate::codegen) fn has_flatten_affixes(&self) -> bool {
+        !self.flatten_prefix.is_empty() || !self.flatten_suffix.is_empty()
+    }
+
+    /// Whether repeated occurrences of this field are buffered and merged before parsing.
+    fn merges_duplicates(&self) -> bool {
+        !self.multiple && self.on_duplicate == OnDuplicate::Merge
+    }
+
+    /// Whether a repeated list occurrence of this field is merged into the previous one when
+    /// the containing struct's occurrences are merged. Fields which keep every occurrence, or
+    /// keep only the first or last, have their occurrences appended instead.
+    pub(in crate::codegen) fn merges_by_key(&self) -> bool {
+        self.as_name().is_some()
+            && !self.multiple
+            && !self.custom_with
+            && matches!(self.on_duplicate, OnDuplicate::Error | OnDuplicate::Merge)
+    }
+
+    /// Generate the path of the function which merges two occurrences of this field. Fields
+    /// read with a custom `with` append the items, as their type's merge may not apply.
+    pub(in crate::codegen) fn merge_fn(&self) -> TokenStream {
+        if self.custom_with {
+            quote!(_darling::util::merge_meta)
+        } else {
+            let ty = self.ty;
+            quote_spanned!(ty.span()=> <#ty as _darling::FromMeta>::merge_duplicate)
         }
     }
 
+    pub fn as_merge_initializer(&'a self) -> MergeInitializer<'a> {
+        MergeInitializer(self)
+    }
+
+    pub fn as_rest_initializer(&'a self) -> RestInitializer<'a> {
+        RestInitializer(self)
+    }
+
     pub fn as_match(&'a self) -> MatchArm<'a> {
         MatchArm(self)
     }
 
+    pub fn as_positional_match(&'a self, index: usize) -> PositionalMatchArm<'a> {
+        PositionalMatchArm { field: self, index }
+    }
+
     pub fn as_initializer(&'a self) -> Initializer<'a> {
         Initializer(self)
     }
@@ -72,6 +279,24 @@ impl<'a> Field<'a> {
     pub fn as_presence_check(&'a self) -> CheckMissing<'a> {
         CheckMissing(self)
     }
+
+    /// Generate an expression which reads the literal from `default_value`, if the field has
+    /// one that wasn't read when deriving, into a `darling::Result` of the field's type.
+    pub(in crate::codegen) fn default_value_read(&self) -> Option<TokenStream> {
+        let value = self.default_value?;
+        let ty = self.ty;
+        Some(quote_spanned! {value.span()=>
+            <#ty as _darling::FromMeta>::from_expr(&_darling::export::syn::parse_quote!(#value))
+        })
+    }
+}
+
+/// Generate `Some(bound)`, or `None` if the bound isn't set.
+fn optional_bound<T: ToTokens>(bound: Option<T>) -> TokenStream {
+    match bound {
+        Some(bound) => quote!(_darling::export::Some(#bound)),
+        None => quote!(_darling::export::None),
+    }
 }
 
 impl UsesTypeParams for Field<'_> {
@@ -93,51 +318,112 @@ impl ToTokens for Declaration<'_> {
         let ident = field.ident;
         let ty = field.ty;
 
+        // The warnings field receives the warnings added after this point.
+        if field.warnings {
+            let start = warnings_start_var(ident);
+            tokens.append_all(quote!(let #start = __warnings.len();));
+            return;
+        }
+
         tokens.append_all(if field.multiple {
-            // This is NOT mutable, as it will be declared mutable only temporarily.
-            quote!(let mut #ident: #ty = _darling::export::Default::default();)
+            // The collection may deduplicate values, so occurrences are counted separately.
+            let count = count_var(ident);
+            let keys = field.key.map(|_| {
+                let keys = keys_var(ident);
+                quote_spanned!(ty.span()=>
+                    let mut #keys: <#ty as _darling::util::KeyedMap>::Spans = _darling::export::Default::default();
+                )
+            });
+            quote! {
+                let mut #ident: #ty = _darling::export::Default::default();
+                let mut #count: usize = 0;
+                #keys
+            }
         } else {
             quote!(let mut #ident: (bool, _darling::export::Option<#ty>) = (false, None);)
         });
 
-        // The flatten field additionally needs a place to buffer meta items
-        // until attribute walking is done, so declare that now.
-        //
-        // We expect there can only be one field marked `flatten`, so it shouldn't
-        // be possible for this to shadow another declaration.
-        if field.flatten {
+        if field.track_span {
+            let span = span_var(ident);
+            tokens.append_all(quote!(let mut #span = _darling::export::None;));
+        }
+
+        if field.merges_duplicates() {
+            let merged = merge_var(ident);
             tokens.append_all(quote! {
-                let mut __flatten = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
+                let mut #merged: _darling::export::Option<_darling::export::syn::Meta> = _darling::export::None;
+            });
+        }
+
+        // The rest field buffers every unclaimed item until attribute walking is done.
+        if field.rest {
+            tokens.append_all(quote! {
+                let mut __rest = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
             });
         }
     }
 }
 
-pub struct FlattenInitializer<'a> {
-    field: &'a Field<'a>,
-    parent_field_names: Vec<&'a str>,
+/// Name of the local variable counting the occurrences of a `multiple` field.
+fn count_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_count", ident)
+}
+
+/// Name of the local variable recording the keys of a keyed `multiple` field, along with the
+/// span of the occurrence which first used each key.
+fn keys_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_keys", ident)
+}
+
+/// Name of the local variable holding the number of warnings that preceded the input read by
+/// a `warnings` field's container.
+fn warnings_start_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_start", ident)
 }
 
-impl ToTokens for FlattenInitializer<'_> {
+/// Name of the local variable holding the merged occurrences of a field.
+fn merge_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_merged", ident)
+}
+
+/// Generates the code which parses the merged occurrences of a field with
+/// `on_duplicate = "merge"`. This emits nothing for other fields.
+pub struct MergeInitializer<'a>(&'a Field<'a>);
+
+impl ToTokens for MergeInitializer<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
-            field,
-            parent_field_names,
-        } = self;
-        let ident = field.ident;
+        let field = self.0;
+        if !field.merges_duplicates() {
+            return;
+        }
 
-        let add_parent_fields = if parent_field_names.is_empty() {
-            None
-        } else {
-            Some(quote! {
-                .map_err(|e| e.add_sibling_alts_for_unknown_field(&[#(#parent_field_names),*]))
-            })
-        };
+        let ident = field.ident;
+        let merged = merge_var(ident);
+        let extractor = field.as_extractor({
+            let name_str = &field.name_in_attr;
+            quote!(#name_str)
+        });
 
         tokens.append_all(quote! {
+            if let _darling::export::Some(ref __inner) = #merged {
+                #ident = (true, __errors.handle(#extractor));
+            }
+        });
+    }
+}
+
+/// Generates the code which converts the buffered unclaimed items into the `rest` field.
+pub struct RestInitializer<'a>(&'a Field<'a>);
+
+impl ToTokens for RestInitializer<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = self.0.ident;
+        let ty = self.0.ty;
+
+        tokens.append_all(quote_spanned! {ty.span()=>
             #ident = (true,
                 __errors.handle(
-                    _darling::FromMeta::from_list(&__flatten) #add_parent_fields
+                    <#ty as _darling::util::FromRest>::from_rest(__rest)
                     )
                 );
         });
@@ -147,20 +433,12 @@ impl ToTokens for FlattenInitializer<'_> {
 /// Represents an individual field in the match.
 pub struct MatchArm<'a>(&'a Field<'a>);
 
-impl ToTokens for MatchArm<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl MatchArm<'_> {
+    /// Generate the code which stores the value of `__inner` into the field.
+    fn body(&self) -> TokenStream {
         let field = self.0;
-
-        // Skipped and flattened fields cannot be populated by a meta
-        // with their name, so they do not have a match arm.
-        if field.skip || field.flatten {
-            return;
-        }
-
         let name_str = &field.name_in_attr;
         let ident = field.ident;
-        let with_callable = &field.with_callable;
-        let post_transform = field.post_transform.as_ref();
 
         // Errors include the location of the bad input, so we compute that here.
         // Fields that take multiple values add the index of the error for convenience,
@@ -168,48 +446,185 @@ impl ToTokens for MatchArm<'_> {
         let location = if field.multiple {
             // we use the local variable `len` here because location is accessed via
             // a closure, and the borrow checker gets very unhappy if we try to immutably
-            // borrow `#ident` in that closure when it was declared `mut` outside.
+            // borrow the count in that closure when it was declared `mut` outside.
             quote!(&format!("{}[{}]", #name_str, __len))
         } else {
             quote!(#name_str)
         };
 
-        // Give darling's generated code the span of the `with_callable` so that if the target
-        // type doesn't impl FromMeta, darling's immediate user gets a properly-spanned error.
-        //
-        // Within the generated code, add the span immediately on extraction failure, so that it's
-        // as specific as possible.
-        // The behavior of `with_span` makes this safe to do; if the child applied an
-        // even-more-specific span, our attempt here will not overwrite that and will only cost
-        // us one `if` check.
-        let extractor = quote_spanned!(with_callable.span()=>
-        _darling::export::identity::<fn(&_darling::export::syn::Meta) -> _darling::Result<_>>(#with_callable)(__inner)
-            #post_transform
-            .map_err(|e| e.with_span(&__inner).at(#location))
-        );
+        let deprecation = field.deprecated.map(|note| {
+            let message = deprecation_message("field", &field.name_in_attr, note);
+            quote! {
+                __warnings.push(_darling::error::Warning::new(#message).with_span(__inner.path()));
+            }
+        });
 
-        tokens.append_all(if field.multiple {
-                quote!(
-                    #name_str => {
-                        // Store the index of the name we're assessing in case we need
-                        // it for error reporting.
-                        let __len = #ident.len();
-                        if let _darling::export::Some(__val) = __errors.handle(#extractor) {
-                            #ident.push(__val)
+        let track_span = if field.track_span {
+            let span = span_var(ident);
+            Some(quote! {
+                if #span.is_none() {
+                    #span = _darling::export::Some(_darling::export::syn::spanned::Spanned::span(__inner.path()));
+                }
+            })
+        } else {
+            None
+        };
+
+        let extractor = field.as_extractor(location.clone());
+
+        if field.multiple {
+            let ty = field.ty;
+            let count = count_var(ident);
+            let store = if let Some(key) = field.key {
+                let keys = keys_var(ident);
+                let msg = format_names(
+                    &format!("Each `{{}}` must have a unique `{}`", key),
+                    &[name_str],
+                );
+                let read_key = quote_spanned!(key.span()=> __val.#key.clone());
+                quote! {
+                    if let _darling::export::Some(__val) = __errors.handle(#extractor) {
+                        // Name the value's type so that its key field can be read.
+                        let __val: <#ty as _darling::util::KeyedMap>::Value = __val;
+                        let __key = #read_key;
+                        if #ident.contains_key(&__key) {
+                            // Point at the first occurrence too, but only the first time it's repeated.
+                            if let _darling::export::Some(__first) = #keys.get_mut(&__key) {
+                                if !__first.1 {
+                                    __first.1 = true;
+                                    __errors.push(_darling::Error::custom(#msg).with_span(&__first.0));
+                                }
+                            }
+                            __errors.push(_darling::Error::custom(#msg).with_span(&__inner));
+                        } else {
+                            #keys.insert(
+                                _darling::export::Clone::clone(&__key),
+                                (_darling::export::syn::spanned::Spanned::span(__inner), false),
+                            );
+                            #ident.insert(__key, __val);
                         }
                     }
-                )
+                }
             } else {
-                quote!(
-                    #name_str => {
-                        if !#ident.0 {
-                            #ident = (true, __errors.handle(#extractor));
-                        } else {
-                            __errors.push(_darling::Error::duplicate_field(#name_str).with_span(&__inner));
+                quote! {
+                    if let _darling::export::Some(__val) = __errors.handle(#extractor) {
+                        #[allow(unused)]
+                        use _darling::autoref_specialization::{
+                            SpecSetInsert as _,
+                            SpecExtendAll as _
+                        };
+
+                        if !(&_darling::export::PhantomData::<#ty>).collect_tag().insert(&mut #ident, __val) {
+                            __errors.push(_darling::Error::duplicate_value()
+                                .with_span(&__inner)
+                                .at(#location));
                         }
                     }
-                )
-            });
+                }
+            };
+            // Occurrences past the maximum are reported rather than parsed.
+            let store = match field.max_items {
+                Some(max) => quote! {
+                    if __len >= #max {
+                        __errors.push(_darling::Error::too_many_items(#max).with_span(&__inner).at(#location));
+                    } else {
+                        #store
+                    }
+                },
+                None => store,
+            };
+
+            quote!(
+                #deprecation
+                #track_span
+                // Store the index of the occurrence we're assessing in case we need
+                // it for error reporting.
+                let __len = #count;
+                #count += 1;
+                #store
+            )
+        } else {
+            let store = match field.on_duplicate {
+                OnDuplicate::Error => quote! {
+                    if !#ident.0 {
+                        #ident = (true, __errors.handle(#extractor));
+                    } else {
+                        __errors.push(_darling::Error::duplicate_field(#name_str).with_span(&__inner));
+                    }
+                },
+                OnDuplicate::First => quote! {
+                    if !#ident.0 {
+                        #ident = (true, __errors.handle(#extractor));
+                    }
+                },
+                OnDuplicate::Last => quote! {
+                    #ident = (true, __errors.handle(#extractor));
+                },
+                // Merged fields are parsed once all occurrences have been seen.
+                OnDuplicate::Merge => {
+                    let merged = merge_var(ident);
+                    let merge_fn = field.merge_fn();
+                    quote! {
+                        match #merged {
+                            _darling::export::Some(ref mut __merged) => {
+                                __errors.handle(#merge_fn(__merged, __inner));
+                            }
+                            _darling::export::None => {
+                                #merged = _darling::export::Some(__inner.clone());
+                            }
+                        }
+                    }
+                }
+            };
+
+            quote!(
+                #deprecation
+                #track_span
+                #store
+            )
+        }
+    }
+}
+
+impl ToTokens for MatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field = self.0;
+
+        // Skipped, flattened, rest, and warnings fields cannot be populated by a meta
+        // with their name, so they do not have a match arm.
+        if field.as_name().is_none() {
+            return;
+        }
+
+        let pattern = names_pattern(field.as_names(), field.case_insensitive);
+        let body = self.body();
+
+        tokens.append_all(quote!(
+            #pattern => {
+                #body
+            }
+        ));
+    }
+}
+
+/// Represents a positional field in the match on the index of a positional argument.
+///
+/// The generated code expects `__inner` to be a `syn::Meta::NameValue` holding the argument.
+pub struct PositionalMatchArm<'a> {
+    field: &'a Field<'a>,
+    index: usize,
+}
+
+impl ToTokens for PositionalMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let index = self.index;
+        let body = MatchArm(self.field).body();
+
+        tokens.append_all(quote!(
+            #index => {
+                #body
+            }
+        ));
     }
 }
 
@@ -220,9 +635,13 @@ impl ToTokens for Initializer<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field = self.0;
         let ident = field.ident;
-        tokens.append_all(if field.multiple {
+        tokens.append_all(if field.warnings {
+            let start = warnings_start_var(ident);
+            quote!(#ident: __warnings[#start..].to_vec())
+        } else if field.multiple {
             if let Some(ref expr) = field.default_expression {
-                quote_spanned!(expr.span()=> #ident: if !#ident.is_empty() {
+                let count = count_var(ident);
+                quote_spanned!(expr.span()=> #ident: if #count > 0 {
                     #ident
                 } else {
                     #expr
@@ -247,11 +666,60 @@ pub struct CheckMissing<'a>(&'a Field<'a>);
 
 impl ToTokens for CheckMissing<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if !self.0.multiple && self.0.default_expression.is_none() {
+        if self.0.warnings {
+            return;
+        }
+
+        if let Some(read) = self.0.default_value_read() {
+            let ident = self.0.ident;
+            let name_in_attr = &self.0.name_in_attr;
+            let (absent, store) = if self.0.multiple {
+                let count = count_var(ident);
+                (quote!(#count == 0), quote!(#ident = __value;))
+            } else {
+                (
+                    quote!(!#ident.0),
+                    quote!(#ident.1 = _darling::export::Some(__value);),
+                )
+            };
+            tokens.append_all(quote! {
+                if #absent {
+                    if let _darling::export::Some(__value) = __errors.handle(#read.map_err(|e| e.at(#name_in_attr))) {
+                        #store
+                    }
+                }
+            });
+        }
+
+        if self.0.multiple {
+            if let Some(min) = self.0.min_items.filter(|min| *min > 0) {
+                let count = count_var(self.0.ident);
+                let name_in_attr = &self.0.name_in_attr;
+                // A field with a default only checks its minimum when it appears in the input.
+                let absent_ok = (self.0.default_expression.is_some()
+                    || self.0.default_value.is_some())
+                .then(|| quote!(#count > 0 &&));
+
+                tokens.append_all(quote! {
+                    if #absent_ok #count < #min {
+                        __errors.push(_darling::Error::too_few_items(#min).at(#name_in_attr));
+                    }
+                });
+            }
+        } else if self.0.default_expression.is_none()
+            && self.0.default_value.is_none()
+            && self.0.default_from.is_none()
+        {
             let ident = self.0.ident;
             let ty = self.0.ty;
             let name_in_attr = &self.0.name_in_attr;
             let from_none_call = super::from_none_call(ty);
+            let missing = if self.0.positional {
+                let msg = format_names("Missing positional argument `{}`", &[name_in_attr]);
+                quote!(_darling::Error::custom(#msg))
+            } else {
+                quote!(_darling::Error::missing_field(#name_in_attr))
+            };
 
             tokens.append_all(quote! {
                 if !#ident.0 {
@@ -260,7 +728,7 @@ impl ToTokens for CheckMissing<'_> {
                             #ident.1 = _darling::export::Some(__type_fallback);
                         }
                         _darling::export::None => {
-                            __errors.push(_darling::Error::missing_field(#name_in_attr))
+                            __errors.push(#missing)
                         }
                     }
                 }
@@ -0,0 +1,247 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::Ident;
+
+use crate::codegen::Field;
+
+/// Declare the buffer which holds unclaimed meta items until attribute walking is done,
+/// if any of the fields is marked `flatten`.
+pub(in crate::codegen) fn flatten_declaration(fields: &[Field<'_>]) -> Option<TokenStream> {
+    if fields.iter().any(|f| f.flatten) {
+        Some(quote! {
+            let mut __flatten = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
+        })
+    } else {
+        None
+    }
+}
+
+/// Name of the local variable holding the keys accepted by a flattened field's type.
+fn names_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_names", ident)
+}
+
+/// Name of the local variable holding the unclaimed items routed to a flattened field.
+fn items_var(ident: &Ident) -> Ident {
+    format_ident!("__{}_items", ident)
+}
+
+/// Generates the code which gives the unclaimed meta items in `__flatten` to the fields
+/// marked `flatten`, and marks those fields as having been seen.
+///
+/// A single flattened field receives every unclaimed item. When there are several, each item
+/// goes to the field whose type accepts the item's key, according to `FromMeta::accepts_field`;
+/// items that no type accepts go to the first field whose type doesn't list its keys, or are reported as unknown
+/// unless the container allows unknown fields. An item whose key more than one of the types
+/// accepts is reported as ambiguous.
+///
+/// A field declared with `flatten(prefix = "...", suffix = "...")` only receives items whose keys
+/// carry those affixes, and its type sees the keys without them.
+///
+/// Errors from a flattened type which can't point at any item, such as a flattened enum with
+/// none of its variants set, are spanned on `container` when it is given.
+pub struct FlattenFields<'a> {
+    fields: &'a [Field<'a>],
+    allow_unknown_fields: bool,
+    container: Option<&'a TokenStream>,
+}
+
+impl<'a> FlattenFields<'a> {
+    pub fn new(
+        fields: &'a [Field<'a>],
+        allow_unknown_fields: bool,
+        container: Option<&'a TokenStream>,
+    ) -> Self {
+        Self {
+            fields,
+            allow_unknown_fields,
+            container,
+        }
+    }
+}
+
+impl ToTokens for FlattenFields<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let flattened = self.fields.iter().filter(|f| f.flatten).collect::<Vec<_>>();
+        let parent_field_names = self
+            .fields
+            .iter()
+            .flat_map(Field::as_names)
+            .collect::<Vec<_>>();
+
+        let add_parent_fields = if parent_field_names.is_empty() {
+            None
+        } else {
+            Some(quote! {
+                .map_err(|e| e.add_sibling_alts_for_unknown_field(&[#(#parent_field_names),*]))
+            })
+        };
+        let add_parent_fields = match self.container {
+            Some(container) => quote! {
+                #add_parent_fields
+                .map_err(|e| e.with_span(&#container))
+            },
+            None => quote!(#add_parent_fields),
+        };
+
+        match flattened.as_slice() {
+            [] => {}
+            [field] if !field.has_flatten_affixes() => {
+                let ident = field.ident;
+                tokens.append_all(quote! {
+                    #ident = (true,
+                        __errors.handle(
+                            _darling::FromMeta::from_flattened(&__flatten) #add_parent_fields
+                            )
+                        );
+                });
+            }
+            _ => {
+                let names = flattened
+                    .iter()
+                    .map(|f| names_var(f.ident))
+                    .collect::<Vec<_>>();
+                let items = flattened
+                    .iter()
+                    .map(|f| items_var(f.ident))
+                    .collect::<Vec<_>>();
+                let idents = flattened.iter().map(|f| f.ident);
+                let field_names = flattened.iter().map(|f| {
+                    let ty = f.ty;
+                    if f.has_flatten_affixes() {
+                        let (prefix, suffix) = (f.flatten_prefix, f.flatten_suffix);
+                        quote! {
+                            <#ty as _darling::FromMeta>::field_names().map(|__names| {
+                                __names
+                                    .into_iter()
+                                    .map(|__name| format!("{}{}{}", #prefix, __name, #suffix))
+                                    .collect::<_darling::export::Vec<_>>()
+                            })
+                        }
+                    } else {
+                        quote!(<#ty as _darling::FromMeta>::field_names())
+                    }
+                });
+                let add_affixes = flattened.iter().map(|f| {
+                    if f.has_flatten_affixes() {
+                        let (prefix, suffix) = (f.flatten_prefix, f.flatten_suffix);
+                        Some(quote!(.map_err(|e| e.add_field_affixes(#prefix, #suffix))))
+                    } else {
+                        None
+                    }
+                });
+
+                // A field with affixes only receives items whose key has them, and the
+                // field's type sees the key with the affixes removed.
+                let push_item = |field: &Field<'_>, items: &Ident| {
+                    if field.has_flatten_affixes() {
+                        let (prefix, suffix) = (field.flatten_prefix, field.flatten_suffix);
+                        quote! {
+                            if let _darling::export::Some(__stripped) = _darling::util::strip_key_affixes(&__item, #prefix, #suffix) {
+                                #items.push(__stripped);
+                                continue;
+                            }
+                        }
+                    } else {
+                        quote! {
+                            #items.push(__item);
+                            continue;
+                        }
+                    }
+                };
+                let claims = flattened.iter().map(|f| {
+                    let ty = f.ty;
+                    if f.has_flatten_affixes() {
+                        let (prefix, suffix) = (f.flatten_prefix, f.flatten_suffix);
+                        quote! {
+                            __name
+                                .strip_prefix(#prefix)
+                                .and_then(|__name| __name.strip_suffix(#suffix))
+                                .map_or(false, <#ty as _darling::FromMeta>::accepts_field)
+                        }
+                    } else {
+                        quote!(<#ty as _darling::FromMeta>::accepts_field(&__name))
+                    }
+                });
+                let claimed =
+                    flattened
+                        .iter()
+                        .zip(&items)
+                        .enumerate()
+                        .map(|(index, (field, items))| {
+                            let push = push_item(field, items);
+                            quote! {
+                                if __claims[#index] {
+                                    #push
+                                }
+                            }
+                        });
+                let open = flattened.iter().zip(names.iter().zip(&items)).map(
+                    |(field, (names, items))| {
+                        let push = push_item(field, items);
+                        quote! {
+                            if #names.is_none() {
+                                #push
+                            }
+                        }
+                    },
+                );
+                let flattened_names = flattened.iter().map(|f| f.ident.to_string());
+
+                // Items that no flattened field accepts are dropped if unknown fields are allowed.
+                let unknown = if self.allow_unknown_fields {
+                    None
+                } else {
+                    Some(quote! {
+                        let mut __alts: _darling::export::Vec<&str> = _darling::export::Vec::from([#(#parent_field_names),*]);
+                        #(
+                            __alts.extend(#names.iter().flatten().map(|__name| __name.as_str()));
+                        )*
+                        __errors.push(_darling::Error::unknown_field_with_alts(&__name, &__alts).with_span(&__item));
+                    })
+                };
+
+                tokens.append_all(quote! {
+                    #(
+                        let #names = #field_names;
+                        let mut #items = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
+                    )*
+
+                    for __item in __flatten {
+                        let __name = match __item {
+                            _darling::export::NestedMeta::Meta(ref __meta) => _darling::util::path_to_string(__meta.path()),
+                            _darling::export::NestedMeta::Lit(_) => _darling::export::String::new(),
+                        };
+
+                        // Two flattened types accepting the same key would make routing ambiguous.
+                        let __claims = [#(#claims),*];
+                        let mut __claimants = [#(#flattened_names),*]
+                            .iter()
+                            .zip(__claims.iter())
+                            .filter(|(_, __claim)| **__claim)
+                            .map(|(__field, _)| *__field);
+                        if let (_darling::export::Some(__first), _darling::export::Some(__second)) = (__claimants.next(), __claimants.next()) {
+                            __errors.push(
+                                _darling::Error::custom(format!("Flattened fields `{}` and `{}` both accept `{}`", __first, __second, __name))
+                                    .with_span(&__item)
+                            );
+                            continue;
+                        }
+
+                        #(#claimed)*
+                        #(#open)*
+                        #unknown
+                    }
+
+                    #(
+                        #idents = (true,
+                            __errors.handle(
+                                _darling::FromMeta::from_flattened(&#items) #add_affixes #add_parent_fields
+                                )
+                            );
+                    )*
+                });
+            }
+        }
+    }
+}
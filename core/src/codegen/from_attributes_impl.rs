@@ -45,18 +45,21 @@ impl ToTokens for FromAttributesImpl<'_> {
             .flatten();
 
         let inits = self.base.initializers();
+        let default_from = self.base.default_from();
         let default = self.base.fallback_decl();
 
         let grab_attrs = self.extractor();
 
         let declare_errors = self.base.declare_errors();
-        let require_fields = self.base.require_fields();
+        let declare_warnings = self.base.declare_warnings();
+        let require_fields = self.base.require_fields(None);
         let check_errors = self.base.check_errors();
 
         self.wrap(
             quote! {
                 fn from_attributes(#input: &[_darling::export::syn::Attribute]) -> _darling::Result<Self> {
                     #declare_errors
+                    #declare_warnings
 
                     #grab_attrs
 
@@ -65,6 +68,7 @@ impl ToTokens for FromAttributesImpl<'_> {
                     #check_errors
 
                     #default
+                    #default_from
 
                     _darling::export::Ok(#ty_ident {
                         #(#forwarded_fields,)*
@@ -110,6 +110,7 @@ impl ToTokens for FromDeriveInputImpl<'_> {
         };
 
         let inits = self.base.initializers();
+        let default_from = self.base.default_from();
         let default = if self.from_ident {
             quote!(let __default: Self = _darling::export::From::from(#input.ident.clone());)
         } else {
@@ -119,13 +120,15 @@ impl ToTokens for FromDeriveInputImpl<'_> {
         let grab_attrs = self.extractor();
 
         let declare_errors = self.base.declare_errors();
-        let require_fields = self.base.require_fields();
+        let declare_warnings = self.base.declare_warnings();
+        let require_fields = self.base.require_fields(Some(quote!(#input.ident)));
         let check_errors = self.base.check_errors();
 
         self.wrap(
             quote! {
                 fn from_derive_input(#input: &_darling::export::syn::DeriveInput) -> _darling::Result<Self> {
                     #declare_errors
+                    #declare_warnings
 
                     #grab_attrs
 
@@ -138,6 +141,7 @@ impl ToTokens for FromDeriveInputImpl<'_> {
                     #check_errors
 
                     #default
+                    #default_from
 
                     _darling::export::Ok(#ty_ident {
                         #(#forwarded_fields,)*
@@ -27,10 +27,12 @@ impl ToTokens for FromFieldImpl<'_> {
         let input = self.param_name();
 
         let error_declaration = self.base.declare_errors();
-        let require_fields = self.base.require_fields();
+        let warnings_declaration = self.base.declare_warnings();
+        let require_fields = self.base.require_fields(Some(input.clone()));
         let error_check = self.base.check_errors();
 
         let initializers = self.base.initializers();
+        let default_from = self.base.default_from();
 
         let default = if self.from_ident {
             quote!(let __default: Self = _darling::export::From::from(#input.ident.clone());)
@@ -61,6 +63,7 @@ impl ToTokens for FromFieldImpl<'_> {
             quote! {
                 fn from_field(#input: &_darling::export::syn::Field) -> _darling::Result<Self> {
                     #error_declaration
+                    #warnings_declaration
 
                     #grab_attrs
 
@@ -69,6 +72,7 @@ impl ToTokens for FromFieldImpl<'_> {
                     #error_check
 
                     #default
+                    #default_from
 
                     _darling::export::Ok(Self {
                         #(#forwarded_fields,)*
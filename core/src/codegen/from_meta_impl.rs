@@ -5,7 +5,9 @@ use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
 
 use crate::ast::{Data, Fields, Style};
-use crate::codegen::{Field, OuterFromImpl, TraitImpl, Variant};
+use crate::codegen::{
+    format_names, names_pattern, Field, FieldsGen, OuterFromImpl, TraitImpl, Variant,
+};
 use crate::util::Callable;
 
 pub struct FromMetaImpl<'a> {
@@ -14,6 +16,10 @@ pub struct FromMetaImpl<'a> {
     pub from_none: Option<&'a Callable>,
     pub from_expr: Option<&'a Callable>,
     pub derive_syn_parse: bool,
+    /// The key holding the variant name for internally-tagged enums.
+    pub tag: Option<&'a str>,
+    /// Whether the enum is untagged, and so should try each variant against the input in turn.
+    pub untagged: bool,
 }
 
 impl ToTokens for FromMetaImpl<'_> {
@@ -53,7 +59,14 @@ impl ToTokens for FromMetaImpl<'_> {
             let ty_ident = base.ident;
             let impl_block = quote!(
                 fn from_meta(__item: &_darling::export::syn::Meta) -> _darling::Result<Self> {
-                    _darling::FromMeta::from_meta(__item)
+                    Self::from_meta_with_warnings(__item, &mut _darling::export::Vec::new())
+                }
+
+                fn from_meta_with_warnings(
+                    __item: &_darling::export::syn::Meta,
+                    __warnings: &mut _darling::export::Vec<_darling::error::Warning>,
+                ) -> _darling::Result<Self> {
+                    _darling::FromMeta::from_meta_with_warnings(__item, __warnings)
                         .map_err(|e| e.with_span(&__item))
                         .map(|value| #ty_ident { #member: value })
                 }
@@ -76,21 +89,26 @@ impl ToTokens for FromMetaImpl<'_> {
                     }
                 )
             }
-            Data::Struct(Fields {
-                style: Style::Tuple,
-                ..
-            }) => {
-                panic!("Multi-field tuples are not supported");
-            }
-            Data::Struct(ref data) => {
-                let inits = data.fields.iter().map(Field::as_initializer);
-                let declare_errors = base.declare_errors();
-                let require_fields = base.require_fields();
-                let check_errors = base.check_errors();
-                let decls = base.local_declarations();
-                let core_loop = base.core_loop();
+            // Tuple structs read their fields from the list in order.
+            Data::Struct(
+                ref data @ Fields {
+                    style: Style::Tuple,
+                    ..
+                },
+            ) => {
                 let default = base.fallback_decl();
                 let post_transform = base.post_transform_call();
+                let body =
+                    FieldsGen::new(data, false).tuple_from_list(quote!(Self), base.check_errors());
+                let from_list = from_list_with_warnings(
+                    quote!(__items),
+                    quote! {
+                        #default
+
+                        (#body) #post_transform
+                    },
+                    None,
+                );
 
                 quote!(
                     #from_word
@@ -99,8 +117,24 @@ impl ToTokens for FromMetaImpl<'_> {
 
                     #from_expr
 
-                    fn from_list(__items: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
-
+                    #from_list
+                )
+            }
+            Data::Struct(ref data) => {
+                let inits = data.fields.iter().map(Field::as_initializer);
+                let declare_errors = base.declare_errors();
+                let require_fields = base.require_fields(None);
+                let check_errors = base.check_errors();
+                let decls = base.local_declarations();
+                let core_loop = base.core_loop();
+                let default = base.fallback_decl();
+                let default_from = base.default_from();
+                let post_transform = base.post_transform_call();
+                let field_names = field_names(data, base.allow_unknown_fields);
+                let merge_duplicate = merge_duplicate(data);
+                let from_list = from_list_with_warnings(
+                    quote!(__items),
+                    quote! {
                         #decls
 
                         #declare_errors
@@ -113,17 +147,138 @@ impl ToTokens for FromMetaImpl<'_> {
 
                         #default
 
+                        #default_from
+
                         _darling::export::Ok(Self {
                             #(#inits),*
                         }) #post_transform
+                    },
+                    None,
+                );
+
+                quote!(
+                    #from_word
+
+                    #from_none
+
+                    #from_expr
+
+                    #field_names
+
+                    #merge_duplicate
+
+                    #from_list
+                )
+            }
+            Data::Enum(ref variants) if self.untagged => {
+                let attempts = variants.iter().map(Variant::as_untagged_attempt);
+                let no_match = format!(
+                    "Input did not match any variant of untagged enum `{}`",
+                    base.ident
+                );
+
+                quote!(
+                    fn from_meta(__item: &_darling::export::syn::Meta) -> _darling::Result<Self> {
+                        Self::from_meta_with_warnings(__item, &mut _darling::export::Vec::new())
                     }
+
+                    fn from_meta_with_warnings(
+                        __item: &_darling::export::syn::Meta,
+                        __warnings: &mut _darling::export::Vec<_darling::error::Warning>,
+                    ) -> _darling::Result<Self> {
+                        let mut __failures: _darling::export::Vec<(&str, _darling::Error)> = _darling::export::Vec::new();
+
+                        #(#attempts)*
+
+                        // Report why each variant didn't match, located at that variant.
+                        let mut __errors = _darling::export::Vec::new();
+                        __errors.push(_darling::Error::custom(#no_match).with_span(__item));
+                        for (__variant, __err) in __failures {
+                            __errors.push(__err.with_span(__item).at(__variant));
+                        }
+
+                        _darling::export::Err(_darling::Error::multiple(__errors))
+                    }
+
+                    #from_none
+                )
+            }
+            Data::Enum(ref variants) if self.tag.is_some() => {
+                let tag = self.tag.unwrap();
+                let arms = variants.iter().map(Variant::as_tagged_match_arm);
+                let mut names = variants
+                    .iter()
+                    .filter(|v| !v.skip)
+                    .flat_map(Variant::as_names)
+                    .peekable();
+                let unknown_variant_err = if names.peek().is_some() {
+                    quote!(unknown_value_with_alts(__other, &[#(#names),*]))
+                } else {
+                    quote!(unknown_value(__other))
+                };
+
+                let from_list = from_list_with_warnings(
+                    quote!(__outer),
+                    quote! {
+                        // Split the tag from the items which will be read by the selected variant.
+                        let mut __tag = _darling::export::None;
+                        let mut __items = _darling::export::Vec::with_capacity(__outer.len());
+                        for __item in __outer {
+                            if let _darling::export::NestedMeta::Meta(ref __meta) = *__item {
+                                if _darling::util::path_to_string(__meta.path()) == #tag {
+                                    if __tag.is_some() {
+                                        return _darling::export::Err(_darling::Error::duplicate_field(#tag).with_span(__meta));
+                                    }
+
+                                    let __value: _darling::export::String = _darling::FromMeta::from_meta(__meta)
+                                        .map_err(|e| e.with_span(__meta).at(#tag))?;
+                                    __tag = _darling::export::Some((__value, __meta));
+                                    continue;
+                                }
+                            }
+
+                            __items.push(__item.clone());
+                        }
+
+                        let __items = &__items;
+                        let (__tag, __tag_meta) = match __tag {
+                            _darling::export::Some(__tag) => __tag,
+                            _darling::export::None => {
+                                return _darling::export::Err(_darling::Error::missing_field(#tag));
+                            }
+                        };
+
+                        match __tag.as_str() {
+                            #(#arms)*
+                            __other => _darling::export::Err(
+                                _darling::Error::#unknown_variant_err.with_span(__tag_meta).at(#tag)
+                            ),
+                        }
+                    },
+                    None,
+                );
+
+                quote!(
+                    #from_list
+
+                    #from_word
+
+                    #from_none
+
+                    #from_expr
                 )
             }
             Data::Enum(ref variants) => {
                 let unit_arms = variants.iter().map(Variant::as_unit_match_arm);
-
-                let (unknown_variant_err, unknown_unit_variant_err) = if !variants.is_empty() {
-                    let names = variants.iter().map(Variant::as_name);
+                let other = variants.iter().find(|v| v.other && !v.skip);
+
+                // The catch-all variant doesn't have a name of its own in the input.
+                let mut names = variants
+                    .iter()
+                    .filter(|v| !v.other)
+                    .flat_map(Variant::as_names)
+                    .peekable();
+                let (unknown_variant_err, unknown_unit_variant_err) = if names.peek().is_some() {
                     let names = quote!(&[#(#names),*]);
                     (
                         quote! {
@@ -142,10 +297,114 @@ impl ToTokens for FromMetaImpl<'_> {
                     )
                 };
 
+                let unknown_variant = quote!(
+                    _darling::export::Err(_darling::Error::#unknown_variant_err.with_span(__nested))
+                );
+                let unknown_variant =
+                    match other {
+                        // Unknown words, such as `mode(custom)`, are passed to the catch-all variant
+                        // as a string with the span of the word.
+                        Some(other) => {
+                            let from_word = other.as_other_from_lit(true, quote! {
+                            _darling::export::syn::Lit::Str(_darling::export::syn::LitStr::new(
+                                __other,
+                                _darling::export::syn::spanned::Spanned::span(__path),
+                            ))
+                        });
+                            quote!(
+                                if let _darling::export::syn::Meta::Path(ref __path) = *__nested {
+                                    #from_word
+                                } else {
+                                    #unknown_variant
+                                }
+                            )
+                        }
+                        None => unknown_variant,
+                    };
+
+                // `from_string` doesn't have the input's span, so a default token is used to get
+                // the call site span.
+                let unknown_unit_variant = match other {
+                    Some(other) => other.as_other_from_lit(
+                        false,
+                        quote! {
+                            _darling::export::syn::Lit::Str(_darling::export::syn::LitStr::new(
+                                __other,
+                                _darling::export::syn::spanned::Spanned::span(
+                                    &<_darling::export::syn::Token![=]>::default(),
+                                ),
+                            ))
+                        },
+                    ),
+                    None => {
+                        quote!(_darling::export::Err(_darling::Error::#unknown_unit_variant_err))
+                    }
+                };
+
+                // `from_string` doesn't know where the value came from, so string literals are
+                // intercepted here to give the catch-all variant access to their span.
+                let from_value = other.map(|other| {
+                    let known = variants
+                        .iter()
+                        .filter(|v| !v.other && !v.skip)
+                        .map(|v| names_pattern(v.as_names(), v.case_insensitive));
+                    let from_lit = other.as_other_from_lit(false, quote!(*__value));
+
+                    quote!(
+                        fn from_value(__value: &_darling::export::syn::Lit) -> _darling::Result<Self> {
+                            if let _darling::export::syn::Lit::Str(ref __str) = *__value {
+                                match __str.value().as_str() {
+                                    #(#known => {})*
+                                    _ => return #from_lit,
+                                }
+                            }
+
+                            (match *__value {
+                                _darling::export::syn::Lit::Str(ref __str) => Self::from_string(&__str.value()),
+                                _ => _darling::export::Err(_darling::Error::unexpected_lit_type(__value)),
+                            })
+                            .map_err(|e| e.with_span(__value))
+                        }
+                    )
+                });
+
                 let data_variants = variants.iter().map(Variant::as_data_match_arm);
+                let flattened = match other {
+                    Some(_) => None,
+                    None => Some(flattened_variant(variants)),
+                };
 
-                quote!(
-                    fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                // Values that aren't lists are read by `from_string` and friends, which have no
+                // warnings list, so deprecated variants are reported once the value is known.
+                // A custom `from_expr` may not have been given the variant's name at all.
+                let deprecated_arms = variants
+                    .iter()
+                    .filter(|v| !v.skip)
+                    .filter_map(|v| {
+                        let deprecation = v.deprecation(quote!(__item))?;
+                        let (ty_ident, variant_ident) = (v.ty_ident, v.variant_ident);
+                        Some(quote!(#ty_ident::#variant_ident { .. } => { #deprecation }))
+                    })
+                    .collect::<Vec<_>>();
+                let non_list = if deprecated_arms.is_empty() || self.from_expr.is_some() {
+                    None
+                } else {
+                    Some(quote! {
+                        let __value = Self::from_meta(__item)?;
+                        if let _darling::export::syn::Meta::NameValue(_) = *__item {
+                            #[allow(unreachable_patterns)]
+                            match __value {
+                                #(#deprecated_arms)*
+                                _ => {}
+                            }
+                        }
+                        _darling::export::Ok(__value)
+                    })
+                };
+
+                let from_list = from_list_with_warnings(
+                    quote!(__outer),
+                    quote! {
                         // An enum must have exactly one value inside the parentheses if it's not a unit
                         // match arm.
                         match __outer.len() {
@@ -154,7 +413,7 @@ impl ToTokens for FromMetaImpl<'_> {
                                 if let _darling::export::NestedMeta::Meta(ref __nested) = __outer[0] {
                                     match _darling::util::path_to_string(__nested.path()).as_ref() {
                                         #(#data_variants)*
-                                        __other => _darling::export::Err(_darling::Error::#unknown_variant_err.with_span(__nested))
+                                        __other => #unknown_variant
                                     }
                                 } else {
                                     _darling::export::Err(_darling::Error::unsupported_format("literal"))
@@ -162,15 +421,24 @@ impl ToTokens for FromMetaImpl<'_> {
                             }
                             _ => _darling::export::Err(_darling::Error::too_many_items(1)),
                         }
-                    }
+                    },
+                    non_list,
+                );
+
+                quote!(
+                    #from_list
+
+                    #flattened
 
                     fn from_string(lit: &str) -> _darling::Result<Self> {
                         match lit {
                             #(#unit_arms)*
-                            __other => _darling::export::Err(_darling::Error::#unknown_unit_variant_err)
+                            __other => #unknown_unit_variant
                         }
                     }
 
+                    #from_value
+
                     #from_word
 
                     #from_none
@@ -220,11 +488,7 @@ impl ToTokens for ParseImpl<'_> {
         let from_meta = self.0.trait_path();
         let impl_block = quote! {
             fn parse(input: _darling::export::syn::parse::ParseStream<'_>) -> _darling::export::syn::Result<Self> {
-                use _darling::export::IntoIterator;
-
-                let items = _darling::export::syn::punctuated::Punctuated::<_darling::export::NestedMeta, _darling::export::syn::Token![,]>::parse_terminated(input)?
-                    .into_iter()
-                    .collect::<_darling::export::Vec<_>>();
+                let items = _darling::export::NestedMeta::parse_meta_list(input.parse()?)?;
                 <Self as #from_meta>::from_list(&items).map_err(_darling::export::Into::into)
             }
         };
@@ -232,3 +496,196 @@ impl ToTokens for ParseImpl<'_> {
         self.wrap(impl_block, tokens);
     }
 }
+
+/// Generate `FromMeta::from_list_with_warnings` with `body`, which reads the items in the local
+/// named `items`, along with the `from_list` and `from_meta_with_warnings` impls that call it.
+///
+/// Input which isn't a list is read by `from_meta`, followed by `non_list` if given.
+fn from_list_with_warnings(
+    items: TokenStream,
+    body: TokenStream,
+    non_list: Option<TokenStream>,
+) -> TokenStream {
+    let non_list = non_list.unwrap_or_else(|| quote!(Self::from_meta(__item)));
+
+    quote! {
+        fn from_list(#items: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+            Self::from_list_with_warnings(#items, &mut _darling::export::Vec::new())
+        }
+
+        fn from_list_with_warnings(
+            #items: &[_darling::export::NestedMeta],
+            __warnings: &mut _darling::export::Vec<_darling::error::Warning>,
+        ) -> _darling::Result<Self> {
+            #body
+        }
+
+        fn from_meta_with_warnings(
+            __item: &_darling::export::syn::Meta,
+            __warnings: &mut _darling::export::Vec<_darling::error::Warning>,
+        ) -> _darling::Result<Self> {
+            if let _darling::export::syn::Meta::List(ref __list) = *__item {
+                _darling::export::NestedMeta::parse_meta_list(__list.tokens.clone())
+                    .map_err(_darling::Error::from)
+                    .and_then(|__items| Self::from_list_with_warnings(&__items, __warnings))
+                    .map_err(|e| e.with_span(__item))
+            } else {
+                #non_list
+            }
+        }
+    }
+}
+
+/// Generate `FromMeta::field_names` and `FromMeta::accepts_field` for a struct with named fields,
+/// including the names accepted by its flattened fields. Structs which accept any key keep the
+/// default impls.
+fn field_names(data: &Fields<Field<'_>>, allow_unknown_fields: bool) -> Option<TokenStream> {
+    if allow_unknown_fields || data.iter().any(|f| f.rest) {
+        return None;
+    }
+
+    let names = data.iter().flat_map(Field::as_names);
+    let patterns = data
+        .iter()
+        .filter(|f| f.as_name().is_some())
+        .map(|f| names_pattern(f.as_names(), f.case_insensitive));
+    let flattened = data.iter().filter(|f| f.flatten).collect::<Vec<_>>();
+    let flattened_names = flattened.iter().map(|f| {
+        let ty = f.ty;
+        let (prefix, suffix) = (f.flatten_prefix, f.flatten_suffix);
+        quote! {
+            <#ty as _darling::FromMeta>::field_names()?
+                .into_iter()
+                .map(|__name| format!("{}{}{}", #prefix, __name, #suffix))
+        }
+    });
+    let flattened_accepts = flattened.iter().map(|f| {
+        let ty = f.ty;
+        let (prefix, suffix) = (f.flatten_prefix, f.flatten_suffix);
+        quote! {
+            __name
+                .strip_prefix(#prefix)
+                .and_then(|__name| __name.strip_suffix(#suffix))
+                .map_or(false, <#ty as _darling::FromMeta>::accepts_field)
+        }
+    });
+
+    Some(quote! {
+        fn field_names() -> _darling::export::Option<_darling::export::Vec<_darling::export::String>> {
+            #[allow(unused_mut)]
+            let mut __names = _darling::export::Vec::from([#(_darling::export::String::from(#names)),*]);
+            #(
+                __names.extend(#flattened_names);
+            )*
+            _darling::export::Some(__names)
+        }
+
+        fn accepts_field(__name: &str) -> bool {
+            match __name {
+                #(#patterns => true,)*
+                _ => #(#flattened_accepts ||)* false,
+            }
+        }
+    })
+}
+
+/// Generate `FromMeta::merge_duplicate` for a struct with named fields, which merges repeated
+/// occurrences of its nested list fields key by key. Structs with no such fields keep the
+/// default impl, which appends.
+fn merge_duplicate(data: &Fields<Field<'_>>) -> Option<TokenStream> {
+    let arms = data
+        .iter()
+        .filter(|f| f.merges_by_key())
+        .enumerate()
+        .map(|(index, f)| {
+            let pattern = names_pattern(f.as_names(), f.case_insensitive);
+            let merge_fn = f.merge_fn();
+            quote! {
+                #pattern => _darling::export::Some((#index, #merge_fn as fn(&mut _darling::export::syn::Meta, &_darling::export::syn::Meta) -> _darling::Result<()>)),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if arms.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn merge_duplicate(__target: &mut _darling::export::syn::Meta, __other: &_darling::export::syn::Meta) -> _darling::Result<()> {
+            _darling::util::merge_meta_by_key(__target, __other, |__name| match __name {
+                #(#arms)*
+                _ => _darling::export::None,
+            })
+        }
+    })
+}
+
+/// Generate `FromMeta::field_names`, `FromMeta::accepts_field`, and `FromMeta::from_flattened`
+/// for an enum, so that a `#[darling(flatten)]` field of the enum's type is selected by whichever
+/// variant key appears among the parent's items.
+fn flattened_variant(variants: &[Variant<'_>]) -> TokenStream {
+    let names = variants
+        .iter()
+        .filter(|v| !v.skip)
+        .flat_map(Variant::as_names)
+        .collect::<Vec<_>>();
+    let patterns = variants
+        .iter()
+        .filter(|v| !v.skip)
+        .map(|v| names_pattern(v.as_names(), v.case_insensitive))
+        .collect::<Vec<_>>();
+    let name_refs = names.iter().collect::<Vec<_>>();
+    let list = vec!["`{}`"; names.len()].join(", ");
+    let missing_msg = format_names(&format!("Exactly one of {} must be set", list), &name_refs);
+    let excess_msg = format_names(&format!("Only one of {} may be set", list), &name_refs);
+
+    quote! {
+        fn field_names() -> _darling::export::Option<_darling::export::Vec<_darling::export::String>> {
+            _darling::export::Some(_darling::export::Vec::from([#(_darling::export::String::from(#names)),*]))
+        }
+
+        fn accepts_field(__name: &str) -> bool {
+            match __name {
+                #(#patterns => true,)*
+                _ => false,
+            }
+        }
+
+        fn from_flattened(__items: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+            let mut __errors = _darling::Error::accumulator();
+            let mut __selected = _darling::export::Vec::new();
+
+            for __item in __items {
+                match *__item {
+                    _darling::export::NestedMeta::Meta(ref __meta) => {
+                        let __name = _darling::util::path_to_string(__meta.path());
+                        match __name.as_str() {
+                            #(#patterns => __selected.push(__item),)*
+                            _ => __errors.push(_darling::Error::unknown_field_with_alts(&__name, &[#(#names),*]).with_span(__meta)),
+                        }
+                    }
+                    _darling::export::NestedMeta::Lit(ref __lit) => {
+                        __errors.push(_darling::Error::unsupported_format("literal").with_span(__lit));
+                    }
+                }
+            }
+
+            let __value = match __selected.as_slice() {
+                [] => {
+                    __errors.push(_darling::Error::custom(#missing_msg));
+                    _darling::export::None
+                }
+                [__variant] => __errors.handle(Self::from_list(&[_darling::export::Clone::clone(*__variant)])),
+                _ => {
+                    for __variant in __selected {
+                        __errors.push(_darling::Error::custom(#excess_msg).with_span(__variant));
+                    }
+                    _darling::export::None
+                }
+            };
+
+            __errors.finish()?;
+            _darling::export::Ok(__value.expect("The variant was already checked to have parsed"))
+        }
+    }
+}
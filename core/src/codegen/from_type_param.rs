@@ -21,8 +21,9 @@ impl ToTokens for FromTypeParamImpl<'_> {
         let input = self.param_name();
 
         let error_declaration = self.base.declare_errors();
+        let warnings_declaration = self.base.declare_warnings();
         let grab_attrs = self.extractor();
-        let require_fields = self.base.require_fields();
+        let require_fields = self.base.require_fields(Some(quote!(#input.ident)));
         let error_check = self.base.check_errors();
 
         let default = if self.from_ident {
@@ -45,6 +46,7 @@ impl ToTokens for FromTypeParamImpl<'_> {
         .flatten();
 
         let initializers = self.base.initializers();
+        let default_from = self.base.default_from();
 
         let post_transform = self.base.post_transform_call();
 
@@ -52,6 +54,7 @@ impl ToTokens for FromTypeParamImpl<'_> {
             quote! {
                 fn from_type_param(#input: &_darling::export::syn::TypeParam) -> _darling::Result<Self> {
                     #error_declaration
+                    #warnings_declaration
 
                     #grab_attrs
 
@@ -60,6 +63,7 @@ impl ToTokens for FromTypeParamImpl<'_> {
                     #error_check
 
                     #default
+                    #default_from
 
                     _darling::export::Ok(Self {
                         #(#forwarded_fields,)*
@@ -49,6 +49,7 @@ impl ToTokens for FromVariantImpl<'_> {
         .flatten();
 
         let inits = self.base.initializers();
+        let default_from = self.base.default_from();
         let post_transform = self.base.post_transform_call();
 
         let default = if self.from_ident {
@@ -90,13 +91,15 @@ impl ToTokens for FromVariantImpl<'_> {
         };
 
         let error_declaration = self.base.declare_errors();
-        let require_fields = self.base.require_fields();
+        let warnings_declaration = self.base.declare_warnings();
+        let require_fields = self.base.require_fields(Some(quote!(#input.ident)));
         let error_check = self.base.check_errors();
 
         self.wrap(
             quote!(
                 fn from_variant(#input: &_darling::export::syn::Variant) -> _darling::Result<Self> {
                     #error_declaration
+                    #warnings_declaration
 
                     #extractor
 
@@ -107,6 +110,7 @@ impl ToTokens for FromVariantImpl<'_> {
                     #error_check
 
                     #default
+                    #default_from
 
                     _darling::export::Ok(Self {
                         #(#forwarded_fields,)*
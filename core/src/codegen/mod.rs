@@ -1,8 +1,12 @@
 mod attr_extractor;
+mod attr_name;
 mod attrs_field;
+mod constraints;
 mod default_expr;
+mod default_from;
 mod error;
 mod field;
+mod flatten;
 mod from_attributes_impl;
 mod from_derive_impl;
 mod from_field;
@@ -11,6 +15,7 @@ mod from_none;
 mod from_type_param;
 mod from_variant_impl;
 pub mod ident_field;
+mod name_pattern;
 mod outer_from_impl;
 mod postfix_transform;
 mod trait_impl;
@@ -20,9 +25,14 @@ mod variant_data;
 mod wrap_in_const;
 
 pub(in crate::codegen) use self::attr_extractor::ExtractAttribute;
+pub use self::attr_name::AttrName;
+pub(in crate::codegen) use self::attr_name::{deprecation_message, format_names};
 pub use self::attrs_field::ForwardAttrs;
+pub use self::constraints::{track_constrained_spans, track_validated_spans, ConstraintChecks};
 pub use self::default_expr::DefaultExpression;
+pub use self::default_from::DefaultFromFields;
 pub use self::field::Field;
+pub use self::flatten::FlattenFields;
 pub use self::from_attributes_impl::FromAttributesImpl;
 pub use self::from_derive_impl::FromDeriveInputImpl;
 pub use self::from_field::FromFieldImpl;
@@ -30,6 +40,7 @@ pub use self::from_meta_impl::FromMetaImpl;
 pub use self::from_none::from_none_call;
 pub use self::from_type_param::FromTypeParamImpl;
 pub use self::from_variant_impl::FromVariantImpl;
+pub(in crate::codegen) use self::name_pattern::names_pattern;
 pub use self::outer_from_impl::OuterFromImpl;
 pub use self::postfix_transform::PostfixTransform;
 pub use self::trait_impl::TraitImpl;
@@ -0,0 +1,23 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::codegen::AttrName;
+
+/// Generate the pattern of a match arm on a `&str` which accepts any of `names`.
+///
+/// When `case_insensitive` is set, the arm instead uses a guard which accepts any input that
+/// [`crate::util::names_match`] one of `names`. Names which are computed by the generated code
+/// are also compared in a guard, as they can't appear in a pattern.
+pub(in crate::codegen) fn names_pattern<'a, I>(names: I, case_insensitive: bool) -> TokenStream
+where
+    I: IntoIterator<Item = AttrName<'a>>,
+{
+    let names = names.into_iter().collect::<Vec<_>>();
+    if case_insensitive {
+        quote!(__candidate if #(_darling::util::names_match(__candidate, #names))||*)
+    } else if names.iter().all(|name| name.as_literal().is_some()) {
+        quote!(#(#names)|*)
+    } else {
+        quote!(__candidate if #(__candidate == #names)||*)
+    }
+}
@@ -3,9 +3,12 @@ use quote::quote;
 use syn::{Generics, Ident};
 
 use crate::ast::{Data, Fields};
+use crate::codegen::constraints::span_var;
+use crate::codegen::flatten::flatten_declaration;
 use crate::codegen::{
-    error::{ErrorCheck, ErrorDeclaration},
-    DefaultExpression, Field, FieldsGen, PostfixTransform, Variant,
+    error::{ErrorCheck, ErrorDeclaration, WarningsDeclaration},
+    extract_transparent, ConstraintChecks, DefaultExpression, Field, FieldsGen, FlattenFields,
+    PostfixTransform, Variant,
 };
 use crate::usage::{CollectTypeParams, IdentSet, Purpose};
 
@@ -16,9 +19,13 @@ pub struct TraitImpl<'a> {
     pub data: Data<Variant<'a>, Field<'a>>,
     pub default: Option<DefaultExpression<'a>>,
     pub post_transform: Option<&'a PostfixTransform>,
+    /// A function which checks the finished value and reports problems to an accumulator.
+    pub validate: Option<&'a syn::Expr>,
     pub allow_unknown_fields: bool,
     pub transparent: bool,
     pub krate: Option<&'a syn::Path>,
+    /// Groups of fields where exactly one field from each group must be present.
+    pub one_of: &'a [Vec<Ident>],
 }
 
 impl<'a> TraitImpl<'a> {
@@ -83,6 +90,11 @@ impl<'a> TraitImpl<'a> {
         ErrorDeclaration::default()
     }
 
+    /// Gets the `let` declaration for warnings collected during parsing.
+    pub fn declare_warnings(&self) -> WarningsDeclaration {
+        WarningsDeclaration::default()
+    }
+
     /// Gets the check which performs an early return if errors occurred during parsing.
     pub fn check_errors(&self) -> ErrorCheck<'_> {
         ErrorCheck::default()
@@ -93,14 +105,57 @@ impl<'a> TraitImpl<'a> {
         if let Data::Struct(ref vd) = self.data {
             let vdr = vd.as_ref().map(Field::as_declaration);
             let decls = vdr.fields.as_slice();
-            quote!(#(#decls)*)
+            let flatten = flatten_declaration(&vd.fields);
+            quote!(#(#decls)* #flatten)
         } else {
             quote!()
         }
     }
 
+    /// Generate the calls applied to `darling::Result<Self>` once the value has been built: the
+    /// `validate` function, if any, followed by `map` or `and_then`.
     pub(in crate::codegen) fn post_transform_call(&self) -> Option<TokenStream> {
-        self.post_transform.map(|pt| quote!(#pt))
+        if self.post_transform.is_none() && self.validate.is_none() {
+            return None;
+        }
+
+        let validate = self.validate_call();
+        let post_transform = self.post_transform;
+        Some(quote!(#validate #post_transform))
+    }
+
+    fn validate_call(&self) -> Option<TokenStream> {
+        let validate = self.validate?;
+
+        // Errors the function reports at a field's location are pointed at the key which set
+        // that field, when the field was seen. Transparent structs don't read any keys.
+        let fields = match self.data {
+            Data::Struct(ref fields) if extract_transparent(fields, self.transparent).is_none() => {
+                fields.fields.as_slice()
+            }
+            _ => &[],
+        };
+        let spans = fields.iter().filter(|f| f.track_span).map(|field| {
+            let name = &field.name_in_attr;
+            let span = span_var(field.ident);
+            quote! {
+                let __error = match #span {
+                    _darling::export::Some(__span) => __error.with_span_at(#name, &__span),
+                    _darling::export::None => __error,
+                };
+            }
+        });
+
+        Some(quote! {
+            .and_then(|__value| {
+                let mut __validation = _darling::Error::accumulator();
+                _darling::export::identity::<fn(&Self, &mut _darling::error::Accumulator)>(#validate)(&__value, &mut __validation);
+                __validation.finish_with(__value).map_err(|__error| {
+                    #(#spans)*
+                    __error
+                })
+            })
+        })
     }
 
     /// Generate local variable declaration and initialization for instance from which missing fields will be taken.
@@ -109,26 +164,52 @@ impl<'a> TraitImpl<'a> {
         quote!(#default)
     }
 
-    pub fn require_fields(&self) -> TokenStream {
+    /// Generate the checks which run once every input item has been read.
+    ///
+    /// `container` is an expression for the input being read, which errors that can't point at
+    /// any item, such as an unsatisfied `one_of`, are spanned on. Without it, those errors are left
+    /// for the caller to span.
+    pub fn require_fields(&self, container: Option<TokenStream>) -> TokenStream {
         if let Data::Struct(ref vd) = self.data {
             let check_nones = vd.as_ref().map(Field::as_presence_check);
             let checks = check_nones.fields.as_slice();
 
-            // If a field was marked `flatten`, now is the time to process any unclaimed meta items
-            // and mark the field as having been seen.
-            let flatten_field_init = vd.fields.iter().find(|f| f.flatten).map(|v| {
-                v.as_flatten_initializer(vd.fields.iter().filter_map(Field::as_name).collect())
-            });
+            // Fields which merge repeated occurrences are parsed once every occurrence has been seen.
+            let merge_inits = vd.fields.iter().map(Field::as_merge_initializer);
+
+            // If any fields were marked `flatten`, now is the time to process any unclaimed meta items
+            // and mark those fields as having been seen.
+            let flatten_field_init =
+                FlattenFields::new(&vd.fields, self.allow_unknown_fields, container.as_ref());
+
+            // Likewise, the rest field takes ownership of every item that went unclaimed.
+            let rest_field_init = vd
+                .fields
+                .iter()
+                .find(|f| f.rest)
+                .map(Field::as_rest_initializer);
+
+            let constraint_checks =
+                ConstraintChecks::new(&vd.fields, self.one_of, container.clone());
 
             quote! {
+                #(#merge_inits)*
                 #flatten_field_init
+                #rest_field_init
                 #(#checks)*
+                #constraint_checks
             }
         } else {
             quote!()
         }
     }
 
+    /// Generate the code which computes `default_from` fallbacks, which must come after
+    /// [`Self::fallback_decl`].
+    pub(in crate::codegen) fn default_from(&self) -> TokenStream {
+        self.make_field_ctx().default_from()
+    }
+
     pub(in crate::codegen) fn initializers(&self) -> TokenStream {
         self.make_field_ctx().initializers()
     }
@@ -1,19 +1,23 @@
-use std::borrow::Cow;
-
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::Ident;
 
 use crate::ast::Fields;
 use crate::codegen::error::{ErrorCheck, ErrorDeclaration};
-use crate::codegen::{from_none_call, Field, FieldsGen};
+use crate::codegen::{
+    deprecation_message, from_none_call, names_pattern, AttrName, Field, FieldsGen,
+};
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
+use crate::util::Override;
 
 /// A variant of the enum which is deriving `FromMeta`.
 #[derive(Debug, Clone)]
 pub struct Variant<'a> {
     /// The name which will appear in code passed to the `FromMeta` input.
-    pub name_in_attr: Cow<'a, str>,
+    pub name_in_attr: AttrName<'a>,
+
+    /// Additional names which will be accepted in place of `name_in_attr`.
+    pub aliases: &'a [String],
 
     /// The name of the variant which will be returned for a given `name_in_attr`.
     pub variant_ident: &'a Ident,
@@ -29,13 +33,71 @@ pub struct Variant<'a> {
     pub allow_unknown_fields: bool,
 
     pub transparent: bool,
+
+    /// If set, the variant is deprecated and a warning with this note will be reported
+    /// whenever the variant is selected.
+    pub deprecated: Option<&'a Override<String>>,
+
+    /// Whether this is the catch-all variant for unknown values. Such a variant has no
+    /// match arms of its own, and its name is not offered in suggestions.
+    pub other: bool,
+
+    /// Whether the variant's names match the input regardless of case, `_`, and `-`.
+    pub case_insensitive: bool,
 }
 
 impl<'a> Variant<'a> {
-    pub fn as_name(&'a self) -> &'a str {
+    pub fn as_name(&'a self) -> &'a AttrName<'a> {
         &self.name_in_attr
     }
 
+    /// Get the primary name and all aliases of this variant, in that order.
+    pub fn as_names(&'a self) -> impl Iterator<Item = AttrName<'a>> {
+        std::iter::once(self.as_name().by_ref())
+            .chain(self.aliases.iter().map(|alias| AttrName::literal(alias)))
+    }
+
+    /// Generate the pattern which matches this variant's names in a match on a `&str`.
+    fn names_pattern(&'a self) -> TokenStream {
+        names_pattern(self.as_names(), self.case_insensitive)
+    }
+
+    /// Generate the statement which adds the deprecation warning for this variant, if any,
+    /// to the local `__warnings`.
+    ///
+    /// `spanned` is an expression for the input node the warning should point at.
+    pub(in crate::codegen) fn deprecation(&self, spanned: TokenStream) -> Option<TokenStream> {
+        let message = deprecation_message("variant", &self.name_in_attr, self.deprecated?);
+        Some(quote! {
+            __warnings.push(_darling::error::Warning::new(#message).with_span(#spanned));
+        })
+    }
+
+    /// Generate an expression which creates this catch-all variant from `lit`, an expression
+    /// of type `syn::Lit`, returning early from the enclosing function if that fails.
+    ///
+    /// If `warn` is set, the deprecation warning for the variant is added to the local
+    /// `__warnings`; otherwise the caller is responsible for it.
+    pub fn as_other_from_lit(&self, warn: bool, lit: TokenStream) -> TokenStream {
+        let ty_ident = self.ty_ident;
+        let variant_ident = self.variant_ident;
+        let (member, _) = super::extract_transparent(&self.data, true)
+            .expect("`other` variants were validated to have one field");
+        let deprecation = if warn {
+            self.deprecation(quote!(__lit))
+        } else {
+            None
+        };
+
+        quote!({
+            let __lit = &#lit;
+            #deprecation
+            _darling::export::Ok(#ty_ident::#variant_ident {
+                #member: _darling::FromMeta::from_value(__lit)?
+            })
+        })
+    }
+
     pub fn as_unit_match_arm(&'a self) -> UnitMatchArm<'a> {
         UnitMatchArm(self)
     }
@@ -43,6 +105,57 @@ impl<'a> Variant<'a> {
     pub fn as_data_match_arm(&'a self) -> DataMatchArm<'a> {
         DataMatchArm(self)
     }
+
+    pub fn as_tagged_match_arm(&'a self) -> TaggedMatchArm<'a> {
+        TaggedMatchArm(self)
+    }
+
+    pub fn as_untagged_attempt(&'a self) -> UntaggedAttempt<'a> {
+        UntaggedAttempt(self)
+    }
+
+    /// Generate an expression which reads this variant's fields from the local `__items`
+    /// and evaluates to a `darling::Result` of the variant.
+    fn read_items(&self, check_errors: ErrorCheck<'_>) -> TokenStream {
+        let ty_ident = self.ty_ident;
+        let variant_ident = self.variant_ident;
+        let vdg = FieldsGen::new(&self.data, self.allow_unknown_fields);
+
+        if let Some((member, _)) = super::extract_transparent(&self.data, self.transparent) {
+            quote!(
+                _darling::export::Ok(#ty_ident::#variant_ident {
+                    #member: _darling::FromMeta::from_list_with_warnings(__items, __warnings)?
+                })
+            )
+        } else if self.data.is_struct() || self.data.is_unit() {
+            let declare_errors = ErrorDeclaration::default();
+            let require_fields = (!self.data.is_unit()).then(|| vdg.require_fields());
+            let decls = (!self.data.is_unit()).then(|| vdg.declarations());
+            let core_loop = vdg.core_loop();
+            let inits = vdg.initializers();
+            let default_from = (!self.data.is_unit()).then(|| vdg.default_from());
+
+            quote!({
+                #declare_errors
+
+                #decls
+
+                #core_loop
+
+                #require_fields
+
+                #check_errors
+
+                #default_from
+
+                _darling::export::Ok(#ty_ident::#variant_ident {
+                    #inits
+                })
+            })
+        } else {
+            vdg.tuple_from_list(quote!(#ty_ident::#variant_ident), check_errors)
+        }
+    }
 }
 
 impl UsesTypeParams for Variant<'_> {
@@ -58,17 +171,20 @@ impl UsesTypeParams for Variant<'_> {
 /// Code generator for an enum variant in a unit match position.
 /// This is placed in generated `from_string` calls for the parent enum.
 /// Value-carrying variants wrapped in this type will emit code to produce an "unsupported format" error.
+///
+/// `from_string` has nowhere to put warnings, so deprecated variants are reported by the
+/// parent enum's `from_meta_with_warnings` instead.
 pub struct UnitMatchArm<'a>(&'a Variant<'a>);
 
 impl ToTokens for UnitMatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let val: &Variant<'_> = self.0;
 
-        if val.skip {
+        if val.skip || val.other {
             return;
         }
 
-        let name_in_attr = &val.name_in_attr;
+        let pattern = val.names_pattern();
 
         let unsupported_format_error = || {
             quote!(_darling::export::Err(_darling::Error::unsupported_format(
@@ -81,7 +197,7 @@ impl ToTokens for UnitMatchArm<'_> {
             let ty_ident = val.ty_ident;
 
             tokens.append_all(quote!(
-                #name_in_attr => _darling::export::Ok(#ty_ident::#variant_ident),
+                #pattern => _darling::export::Ok(#ty_ident::#variant_ident),
             ));
         } else if let Some((member, field)) = super::extract_transparent(&val.data, val.transparent)
         {
@@ -92,17 +208,15 @@ impl ToTokens for UnitMatchArm<'_> {
             let from_none = from_none_call(field_ty);
 
             tokens.append_all(quote!{
-                #name_in_attr => {
-                    match #from_none {
-                        _darling::export::Some(__value) => _darling::export::Ok(#ty_ident::#variant_ident { #member: __value }),
-                        _darling::export::None => #unsupported_format,
-                    }
-                }
+                #pattern => match #from_none {
+                    _darling::export::Some(__value) => _darling::export::Ok(#ty_ident::#variant_ident { #member: __value }),
+                    _darling::export::None => #unsupported_format,
+                },
             })
         } else {
             let unsupported_format = unsupported_format_error();
             tokens.append_all(quote!(
-                #name_in_attr => #unsupported_format,
+                #pattern => #unsupported_format,
             ));
         }
     }
@@ -117,19 +231,22 @@ impl ToTokens for DataMatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let val: &Variant<'_> = self.0;
 
-        if val.skip {
+        if val.skip || val.other {
             return;
         }
 
         let name_in_attr = &val.name_in_attr;
+        let pattern = val.names_pattern();
         let variant_ident = val.variant_ident;
         let ty_ident = val.ty_ident;
+        let deprecation = val.deprecation(quote!(__nested.path()));
 
         if val.data.is_unit() {
             // Allow unit variants to match a list item if it's just a path with no associated
             // value, e.g. `volume(shout)` is allowed.
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
+                    #deprecation
                     if let _darling::export::syn::Meta::Path(_) = *__nested {
                         _darling::export::Ok(#ty_ident::#variant_ident)
                     } else {
@@ -141,53 +258,120 @@ impl ToTokens for DataMatchArm<'_> {
             return;
         }
 
-        let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields);
-
         if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent) {
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
+                    #deprecation
                     _darling::export::Ok(
                         #ty_ident::#variant_ident {
-                            #member: _darling::FromMeta::from_meta(__nested)
+                            #member: _darling::FromMeta::from_meta_with_warnings(__nested, __warnings)
                                 .map_err(|e| e.at(#name_in_attr))?
                         }
                     )
                 }
             ));
-        } else if val.data.is_struct() {
-            let declare_errors = ErrorDeclaration::default();
-            let check_errors = ErrorCheck::with_location(name_in_attr);
-            let require_fields = vdg.require_fields();
-            let decls = vdg.declarations();
-            let core_loop = vdg.core_loop();
-            let inits = vdg.initializers();
+        } else {
+            let read_items = val.read_items(ErrorCheck::with_location(name_in_attr));
 
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
+                    #deprecation
                     if let _darling::export::syn::Meta::List(ref __data) = *__nested {
                         let __items = _darling::export::NestedMeta::parse_meta_list(__data.tokens.clone())?;
                         let __items = &__items;
 
-                        #declare_errors
-
-                        #decls
-
-                        #core_loop
-
-                        #require_fields
-
-                        #check_errors
-
-                        _darling::export::Ok(#ty_ident::#variant_ident {
-                            #inits
-                        })
+                        #read_items
                     } else {
                         _darling::export::Err(_darling::Error::unsupported_format("non-list"))
                     }
                 }
             ));
-        } else {
-            panic!("Match arms aren't supported for tuple variants yet");
         }
     }
 }
+
+/// Code generator for an enum variant in an internally-tagged match position.
+/// This is placed in generated `from_list` calls for enums with `#[darling(tag = "...")]`,
+/// and reads the variant from the items that remain once the tag has been removed.
+pub struct TaggedMatchArm<'a>(&'a Variant<'a>);
+
+impl ToTokens for TaggedMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let val: &Variant<'_> = self.0;
+
+        if val.skip {
+            return;
+        }
+
+        let pattern = val.names_pattern();
+        let deprecation = val.deprecation(quote!(__tag_meta));
+        let read_items = val.read_items(ErrorCheck::default());
+
+        tokens.append_all(quote!(
+            #pattern => {
+                #deprecation
+                #read_items
+            }
+        ));
+    }
+}
+
+/// Code generator which tries to read an enum variant from the whole input meta item.
+/// This is placed in generated `from_meta` calls for enums with `#[darling(untagged)]`,
+/// and returns from the method if the variant matched, or records why it didn't in `__failures`.
+pub struct UntaggedAttempt<'a>(&'a Variant<'a>);
+
+impl ToTokens for UntaggedAttempt<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let val: &Variant<'_> = self.0;
+
+        if val.skip {
+            return;
+        }
+
+        let ty_ident = val.ty_ident;
+        let variant_ident = val.variant_ident;
+        let variant_name = variant_ident.to_string();
+        let deprecation = val.deprecation(quote!(__item));
+
+        let attempt = if val.data.is_unit() {
+            quote!(
+                <() as _darling::FromMeta>::from_meta(__item).map(|()| #ty_ident::#variant_ident)
+            )
+        } else if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent) {
+            quote!(
+                _darling::FromMeta::from_meta_with_warnings(__item, __warnings)
+                    .map(|__value| #ty_ident::#variant_ident { #member: __value })
+            )
+        } else {
+            let read_items = val.read_items(ErrorCheck::default());
+            quote!(
+                if let _darling::export::syn::Meta::List(ref __data) = *__item {
+                    let __items = _darling::export::NestedMeta::parse_meta_list(__data.tokens.clone())?;
+                    let __items = &__items;
+
+                    #read_items
+                } else {
+                    _darling::export::Err(_darling::Error::unsupported_format("non-list"))
+                }
+            )
+        };
+
+        // Warnings from an attempt are only relevant if the variant is the one that matched.
+        tokens.append_all(quote!(
+            let mut __attempt_warnings = _darling::export::Vec::new();
+            let __result = (|__warnings: &mut _darling::export::Vec<_darling::error::Warning>| -> _darling::Result<Self> {
+                #attempt
+            })(&mut __attempt_warnings);
+
+            match __result {
+                _darling::export::Ok(__value) => {
+                    __warnings.extend(__attempt_warnings);
+                    #deprecation
+                    return _darling::export::Ok(__value);
+                }
+                _darling::export::Err(__err) => __failures.push((#variant_name, __err)),
+            }
+        ));
+    }
+}
@@ -1,8 +1,14 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, ToTokens};
+use syn::Index;
 
 use crate::ast::{Fields, Style};
-use crate::codegen::Field;
+use crate::codegen::error::ErrorCheck;
+use crate::codegen::flatten::flatten_declaration;
+use crate::codegen::{
+    from_none_call, names_pattern, ConstraintChecks, DefaultExpression, DefaultFromFields, Field,
+    FlattenFields,
+};
 
 pub struct FieldsGen<'a> {
     fields: &'a Fields<Field<'a>>,
@@ -26,7 +32,8 @@ impl<'a> FieldsGen<'a> {
                 ..
             } => {
                 let vdr = fields.iter().map(Field::as_declaration);
-                quote!(#(#vdr)*)
+                let flatten = flatten_declaration(fields);
+                quote!(#(#vdr)* #flatten)
             }
             _ => panic!("FieldsGen doesn't support tuples yet"),
         }
@@ -35,9 +42,17 @@ impl<'a> FieldsGen<'a> {
     /// Generate the loop which walks meta items looking for property matches.
     pub(in crate::codegen) fn core_loop(&self) -> TokenStream {
         let arms = self.fields.as_ref().map(Field::as_match);
+        let has_rest = self.fields.iter().any(|f| f.rest);
+        // If there is a rest field, buffer the unknown field so it can be given to
+        // that field once all items have been read.
+        let handle_unknown = if has_rest {
+            quote! {
+                __rest.push(_darling::ast::NestedMeta::Meta(__inner.clone()));
+            }
+        }
         // If there is a flatten field, buffer the unknown field so it can be passed
         // to the flatten function with all other unknown fields.
-        let handle_unknown = if self.fields.iter().any(|f| f.flatten) {
+        else if self.fields.iter().any(|f| f.flatten) {
             quote! {
                 __flatten.push(_darling::ast::NestedMeta::Meta(__inner.clone()));
             }
@@ -48,7 +63,7 @@ impl<'a> FieldsGen<'a> {
         }
         // Otherwise, we're going to push a new spanned error pointing at the field.
         else {
-            let mut names = self.fields.iter().filter_map(Field::as_name).peekable();
+            let mut names = self.fields.iter().flat_map(Field::as_names).peekable();
             // We can't call `unknown_field_with_alts` with an empty slice, or else it fails to
             // infer the type of the slice item.
             let err_fn = if names.peek().is_none() {
@@ -63,23 +78,251 @@ impl<'a> FieldsGen<'a> {
         };
         let arms = arms.iter();
 
-        quote!(
-            for __item in __items {
-                match *__item {
-                    _darling::export::NestedMeta::Meta(ref __inner) => {
-                        let __name = _darling::util::path_to_string(__inner.path());
-                        match __name.as_str() {
-                            #(#arms)*
-                            __other => { #handle_unknown }
-                        }
+        let handle_lit = if has_rest {
+            quote! {
+                __rest.push(_darling::ast::NestedMeta::Lit(__inner.clone()));
+            }
+        } else {
+            quote! {
+                __errors.push(_darling::Error::unsupported_format("literal")
+                    .with_span(__inner));
+            }
+        };
+
+        let named_items = quote! {
+            match *__item {
+                _darling::export::NestedMeta::Meta(ref __inner) => {
+                    let __name = _darling::util::path_to_string(__inner.path());
+                    match __name.as_str() {
+                        #(#arms)*
+                        __other => { #handle_unknown }
                     }
-                    _darling::export::NestedMeta::Lit(ref __inner) => {
-                        __errors.push(_darling::Error::unsupported_format("literal")
-                            .with_span(__inner));
+                }
+                _darling::export::NestedMeta::Lit(ref __inner) => { #handle_lit }
+            }
+        };
+
+        match self.positional_items(has_rest) {
+            Some(positional_items) => quote!(
+                let mut __positional_index: usize = 0;
+                let mut __named_seen = false;
+                for __item in __items {
+                    #positional_items
+                    __named_seen = true;
+                    #named_items
+                }
+            ),
+            None => quote!(
+                for __item in __items {
+                    #named_items
+                }
+            ),
+        }
+    }
+
+    /// Generate the code which assigns leading literals and bare paths to positional fields,
+    /// or `None` if there are no positional fields.
+    ///
+    /// This expects to run at the start of each loop iteration, and skips to the next item
+    /// when the current one was handled as a positional argument. If there is a `rest` field,
+    /// literals beyond the last positional field are left for it rather than reported.
+    fn positional_items(&self, has_rest: bool) -> Option<TokenStream> {
+        let positional = self
+            .fields
+            .iter()
+            .filter(|f| f.positional)
+            .collect::<Vec<_>>();
+
+        if positional.is_empty() {
+            return None;
+        }
+
+        let count = positional.len();
+        let arms = positional
+            .iter()
+            .enumerate()
+            .map(|(index, field)| field.as_positional_match(index));
+        let too_many = format!(
+            "Too many positional arguments: Expected no more than {}",
+            count
+        );
+
+        // A bare path is only positional if it isn't the name of a field, so that
+        // words such as flags keep working in the leading position.
+        let names = self
+            .fields
+            .iter()
+            .filter(|f| f.as_name().is_some())
+            .map(|f| names_pattern(f.as_names(), f.case_insensitive))
+            .collect::<Vec<_>>();
+        let lit_guard = if has_rest {
+            Some(quote!(if __positional_index < #count))
+        } else {
+            None
+        };
+        let is_field_name = if names.is_empty() {
+            quote!(false)
+        } else {
+            quote! {
+                match _darling::util::path_to_string(__path).as_str() {
+                    #(#names => true,)*
+                    _ => false,
+                }
+            }
+        };
+
+        Some(quote! {
+            let __positional = match *__item {
+                _darling::export::NestedMeta::Lit(ref __lit) #lit_guard => {
+                    _darling::export::Some(_darling::export::syn::Expr::Lit(_darling::export::syn::ExprLit {
+                        attrs: _darling::export::Vec::new(),
+                        lit: __lit.clone(),
+                    }))
+                }
+                _darling::export::NestedMeta::Meta(_darling::export::syn::Meta::Path(ref __path))
+                    if !__named_seen && __positional_index < #count && !#is_field_name =>
+                {
+                    _darling::export::Some(_darling::export::syn::Expr::Path(_darling::export::syn::ExprPath {
+                        attrs: _darling::export::Vec::new(),
+                        qself: _darling::export::None,
+                        path: __path.clone(),
+                    }))
+                }
+                _ => _darling::export::None,
+            };
+
+            if let _darling::export::Some(__value) = __positional {
+                if __named_seen {
+                    __errors.push(
+                        _darling::Error::custom("Positional arguments must come before named arguments")
+                            .with_span(__item)
+                    );
+                    continue;
+                }
+
+                // Present the argument to the field's extractor as `name = value`, so that
+                // `with` and `map` behave the same for positional and named arguments.
+                let __inner = &_darling::export::syn::Meta::NameValue(_darling::export::syn::MetaNameValue {
+                    path: _darling::export::syn::Ident::new(
+                        "positional",
+                        _darling::export::syn::spanned::Spanned::span(__item),
+                    ).into(),
+                    eq_token: _darling::export::Default::default(),
+                    value: __value,
+                });
+
+                match __positional_index {
+                    #(#arms)*
+                    _ => {
+                        __errors.push(_darling::Error::custom(#too_many).with_span(__item));
+                    }
+                }
+
+                __positional_index += 1;
+                continue;
+            }
+        })
+    }
+
+    /// Generate a block which reads the fields of a tuple struct or variant, in order, from the
+    /// local `__items` and evaluates to a `darling::Result` of `ctor(field0, field1, ...)`.
+    ///
+    /// Skipped fields don't consume an item. A `__default` local must be in scope if any field
+    /// inherits its default from the container.
+    pub(in crate::codegen) fn tuple_from_list(
+        &self,
+        ctor: TokenStream,
+        check_errors: ErrorCheck<'_>,
+    ) -> TokenStream {
+        let max = self.fields.iter().filter(|f| !f.skip).count();
+        let mut item_index: usize = 0;
+
+        let fields = self.fields.iter().enumerate().map(|(index, field)| {
+            let local = format_ident!("__field_{}", index);
+            let fallback = match field.default_expression {
+                Some(DefaultExpression::Inherit(_)) => {
+                    let member = Index::from(index);
+                    quote!(_darling::export::Some(__default.#member))
+                }
+                Some(ref expr) => quote!(_darling::export::Some(#expr)),
+                None if field.default_value.is_some() => {
+                    let read = field.default_value_read();
+                    quote!(__errors.handle(#read.map_err(|e| e.at(#item_index))))
+                }
+                None => {
+                    let from_none = from_none_call(field.ty);
+                    quote! {
+                        match #from_none {
+                            _darling::export::Some(__type_fallback) => _darling::export::Some(__type_fallback),
+                            _darling::export::None => {
+                                __too_few = _darling::export::Some(#item_index + 1);
+                                _darling::export::None
+                            }
+                        }
                     }
                 }
+            };
+
+            if field.skip {
+                return quote!(let #local = #fallback;);
+            }
+
+            let extractor = field.as_extractor(quote!(#item_index));
+            let decl = quote! {
+                let #local = match __items.get(#item_index) {
+                    _darling::export::Some(__item) => {
+                        // Present literals to the extractor as `name = value`, so that `with` and
+                        // `map` see the same input shape whether or not the item is a literal.
+                        let __inner = &match *__item {
+                            _darling::export::NestedMeta::Meta(ref __meta) => __meta.clone(),
+                            _darling::export::NestedMeta::Lit(ref __lit) => {
+                                _darling::export::syn::Meta::NameValue(_darling::export::syn::MetaNameValue {
+                                    path: _darling::export::syn::Ident::new(
+                                        "positional",
+                                        _darling::export::syn::spanned::Spanned::span(__lit),
+                                    ).into(),
+                                    eq_token: _darling::export::Default::default(),
+                                    value: _darling::export::syn::Expr::Lit(_darling::export::syn::ExprLit {
+                                        attrs: _darling::export::Vec::new(),
+                                        lit: __lit.clone(),
+                                    }),
+                                })
+                            }
+                        };
+
+                        __errors.handle(#extractor)
+                    }
+                    _darling::export::None => #fallback,
+                };
+            };
+
+            item_index += 1;
+            decl
+        }).collect::<Vec<_>>();
+
+        let values = (0..self.fields.len()).map(|index| {
+            let local = format_ident!("__field_{}", index);
+            quote!(#local.expect("Uninitialized fields without defaults were already checked"))
+        });
+
+        quote!({
+            let mut __errors = _darling::Error::accumulator();
+            let mut __too_few: _darling::export::Option<usize> = _darling::export::None;
+
+            if let _darling::export::Some(__extra) = __items.get(#max) {
+                __errors.push(_darling::Error::too_many_items(#max).with_span(__extra));
             }
-        )
+
+            #(#fields)*
+
+            if let _darling::export::Some(__min) = __too_few {
+                __errors.push(_darling::Error::too_few_items(__min));
+            }
+
+            #check_errors
+
+            _darling::export::Ok(#ctor(#(#values),*))
+        })
     }
 
     pub fn require_fields(&self) -> TokenStream {
@@ -89,13 +332,33 @@ impl<'a> FieldsGen<'a> {
                 ref fields,
                 ..
             } => {
+                let merge_inits = fields.iter().map(Field::as_merge_initializer);
+                let flatten_field_init =
+                    FlattenFields::new(fields, self.allow_unknown_fields, None);
+                let rest_field_init = fields
+                    .iter()
+                    .find(|f| f.rest)
+                    .map(Field::as_rest_initializer);
                 let checks = fields.iter().map(Field::as_presence_check);
-                quote!(#(#checks)*)
+                let constraint_checks = ConstraintChecks::new(fields, &[], None);
+                quote! {
+                    #(#merge_inits)*
+                    #flatten_field_init
+                    #rest_field_init
+                    #(#checks)*
+                    #constraint_checks
+                }
             }
             _ => panic!("FieldsGen doesn't support tuples for requirement checks"),
         }
     }
 
+    /// Generate the code which computes `default_from` fallbacks. This must come after the
+    /// container's fallback is declared, since the fields it reads may inherit from it.
+    pub(in crate::codegen) fn default_from(&self) -> TokenStream {
+        DefaultFromFields::new(&self.fields.fields).into_token_stream()
+    }
+
     pub(in crate::codegen) fn initializers(&self) -> TokenStream {
         let inits = self.fields.as_ref().map(Field::as_initializer);
         let inits = inits.iter();
@@ -17,6 +17,7 @@ pub(in crate::error) enum ErrorKind {
     /// An arbitrary error message.
     Custom(String),
     DuplicateField(FieldName),
+    DuplicateValue,
     MissingField(FieldName),
     UnsupportedShape {
         observed: DeriveInputShape,
@@ -54,6 +55,7 @@ impl fmt::Display for ErrorKind {
         match *self {
             Custom(ref s) => s.fmt(f),
             DuplicateField(ref field) => write!(f, "Duplicate field `{}`", field),
+            DuplicateValue => write!(f, "Duplicate value"),
             MissingField(ref field) => write!(f, "Missing field `{}`", field),
             UnknownField(ref field) => field.fmt(f),
             UnsupportedShape {
@@ -183,6 +185,15 @@ impl ErrorUnknownValue {
         }
     }
 
+    /// Apply `rename` to the unknown value, the suggestion, and every alternate.
+    pub fn rename(&mut self, rename: &dyn Fn(&str) -> String) {
+        self.value = rename(&self.value);
+        if let Some((_, did_you_mean)) = &mut self.did_you_mean {
+            *did_you_mean = rename(did_you_mean);
+        }
+        self.alts = self.alts.iter().map(|alt| rename(alt)).collect();
+    }
+
     #[cfg(feature = "diagnostics")]
     pub fn into_diagnostic(self, span: Option<::proc_macro2::Span>) -> ::proc_macro::Diagnostic {
         let mut diag = span
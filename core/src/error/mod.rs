@@ -1,4 +1,4 @@
-//! The `darling::Error` type, the multiple error `Accumulator`, and their internals.
+//! The `darling::Error` type, the multiple error `Accumulator`, non-fatal `Warning`s, and their internals.
 //!
 //! Error handling is one of the core values of `darling`; creating great errors is hard and
 //! never the reason that a proc-macro author started writing their crate. As a result, the
@@ -19,11 +19,14 @@ use syn::{Expr, Lit, LitStr, Path};
 mod child;
 mod kind;
 mod util;
+mod warning;
 
 use crate::util::path_to_string;
 
 use self::kind::{ErrorKind, ErrorUnknownValue, UnknownValuePosition};
 
+pub use self::warning::Warning;
+
 /// An alias of `Result` specific to attribute parsing.
 pub type Result<T> = ::std::result::Result<T, Error>;
 
@@ -92,6 +95,12 @@ impl Error {
         Error::new(ErrorKind::DuplicateField(name.into()))
     }
 
+    /// Creates a new error for a value that appears twice in a collection which doesn't allow
+    /// duplicates, such as a set read by a `#[darling(multiple)]` field.
+    pub fn duplicate_value() -> Self {
+        Error::new(ErrorKind::DuplicateValue)
+    }
+
     /// Creates a new error for a field that appears twice in the input. Helper to avoid repeating
     /// the syn::Path to String conversion.
     pub fn duplicate_field_path(path: &Path) -> Self {
@@ -348,6 +357,40 @@ impl Error {
         self
     }
 
+    /// Tie a span to each error whose outermost location is `location`, if it doesn't already
+    /// have one. Errors inside a multi-error are checked individually.
+    ///
+    /// # Usage
+    /// Derived impls with `#[darling(validate = ...)]` call this for each field that was present
+    /// in the input, so a validation function can write `Error::custom("...").at("port")` and
+    /// the error will point at the `port` key.
+    pub fn with_span_at<T: Spanned>(self, location: &str, node: &T) -> Self {
+        self.span_at(Some(location), node.span())
+    }
+
+    /// Set `span` on errors at `location`, or on every error without a span if `location` is `None`.
+    fn span_at(mut self, location: Option<&str>, span: Span) -> Self {
+        let location = match (location, self.locations.first()) {
+            (Some(location), Some(outermost)) if location != outermost => return self,
+            (Some(_), Some(_)) => None,
+            (location, None) => location,
+            (None, _) => None,
+        };
+
+        if let ErrorKind::Multiple(errors) = self.kind {
+            self.kind = ErrorKind::Multiple(
+                errors
+                    .into_iter()
+                    .map(|error| error.span_at(location, span))
+                    .collect(),
+            );
+        } else if location.is_none() && !self.has_span() {
+            self.span = Some(span);
+        }
+
+        self
+    }
+
     /// Get a span for the error.
     ///
     /// # Return Value
@@ -405,6 +448,42 @@ impl Error {
         self
     }
 
+    /// Add a prefix and suffix to the field names in the error which belong to the level where
+    /// the error is being handled, i.e. the outermost location if the error has one, or else the
+    /// names in unknown, duplicate, and missing field errors, including "did you mean" suggestions.
+    ///
+    /// # Usage
+    /// `flatten(prefix = "...")` fields in derived trait implementations rely on this method so
+    /// that errors from the flattened type show the names the caller actually wrote.
+    pub fn add_field_affixes(self, prefix: &str, suffix: &str) -> Self {
+        self.rename_fields(&|name| format!("{}{}{}", prefix, name, suffix))
+    }
+
+    fn rename_fields(mut self, rename: &dyn Fn(&str) -> String) -> Self {
+        if let Some(outermost) = self.locations.first_mut() {
+            *outermost = rename(outermost);
+            return self;
+        }
+
+        self.kind = match self.kind {
+            ErrorKind::UnknownField(mut unknown_field) => {
+                unknown_field.rename(rename);
+                ErrorKind::UnknownField(unknown_field)
+            }
+            ErrorKind::DuplicateField(name) => ErrorKind::DuplicateField(rename(&name)),
+            ErrorKind::MissingField(name) => ErrorKind::MissingField(rename(&name)),
+            ErrorKind::Multiple(errors) => ErrorKind::Multiple(
+                errors
+                    .into_iter()
+                    .map(|err| err.rename_fields(rename))
+                    .collect(),
+            ),
+            kind => kind,
+        };
+
+        self
+    }
+
     /// Adds a location to the error, such as a field or variant.
     /// Locations must be added in reverse order of specificity. This is a helper function to avoid
     /// repeating path to string logic.
@@ -795,6 +874,12 @@ impl Iterator for IntoIter {
 /// or [`into_inner`](Self::into_inner) has been called, **even if it contains no errors**.
 /// If you want to discard an `Accumulator` that you know to be empty, use `accumulator.finish().unwrap()`.
 ///
+/// # Warnings
+///
+/// An `Accumulator` can also collect non-fatal [`Warning`]s using [`warn`](Self::warn).
+/// Use [`finish_with_warnings`](Self::finish_with_warnings) to get them back alongside the successful value;
+/// the other ways of finishing the accumulator only return its errors.
+///
 /// # Example
 ///
 /// ```
@@ -816,7 +901,10 @@ impl Iterator for IntoIter {
 /// ```
 #[derive(Debug)]
 #[must_use = "Accumulator will panic on drop if not defused."]
-pub struct Accumulator(Option<Vec<Error>>);
+pub struct Accumulator {
+    errors: Option<Vec<Error>>,
+    warnings: Vec<Warning>,
+}
 
 impl Accumulator {
     /// Runs a closure, returning the successful value as `Some`, or collecting the error
@@ -860,8 +948,31 @@ impl Accumulator {
         }
     }
 
+    /// Bundles the collected errors if there were any, or returns the success value along with
+    /// all collected warnings.
+    ///
+    /// If there were errors, only the errors are returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate darling_core as darling;
+    /// use darling::error::Warning;
+    ///
+    /// let mut errors = darling::Error::accumulator();
+    /// errors.warn(Warning::new("`old_name` is deprecated"));
+    ///
+    /// let (value, warnings) = errors.finish_with_warnings(5).unwrap();
+    /// assert_eq!(value, 5);
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn finish_with_warnings<T>(mut self, success: T) -> Result<(T, Vec<Warning>)> {
+        let warnings = std::mem::take(&mut self.warnings);
+        self.finish_with((success, warnings))
+    }
+
     fn errors(&mut self) -> &mut Vec<Error> {
-        match &mut self.0 {
+        match &mut self.errors {
             Some(errors) => errors,
             None => panic!("darling internal error: Accumulator accessed after defuse"),
         }
@@ -872,7 +983,7 @@ impl Accumulator {
     /// This function defuses the drop bomb.
     #[must_use = "Accumulated errors should be handled or propagated to the caller"]
     pub fn into_inner(mut self) -> Vec<Error> {
-        match self.0.take() {
+        match self.errors.take() {
             Some(errors) => errors,
             None => panic!("darling internal error: Accumulator accessed after defuse"),
         }
@@ -883,6 +994,13 @@ impl Accumulator {
         self.errors().push(error)
     }
 
+    /// Add one warning to the collection.
+    ///
+    /// Warnings never cause the accumulator to finish with an error.
+    pub fn warn(&mut self, warning: Warning) {
+        self.warnings.push(warning)
+    }
+
     /// Finish the current accumulation, and if there are no errors create a new `Self` so processing may continue.
     ///
     /// This is shorthand for:
@@ -921,18 +1039,25 @@ impl Accumulator {
     /// }
     /// # validate(&[], &[]).unwrap();
     /// ```
-    pub fn checkpoint(self) -> Result<Accumulator> {
+    pub fn checkpoint(mut self) -> Result<Accumulator> {
         // The doc comment says on success we "return the Accumulator for future use".
         // Actually, we have consumed it by feeding it to finish so we make a fresh one.
         // This is OK since by definition of the success path, it was empty on entry.
+        // Warnings are carried over so they can still be returned by `finish_with_warnings`.
+        let warnings = std::mem::take(&mut self.warnings);
         self.finish()?;
-        Ok(Self::default())
+        let mut next = Self::default();
+        next.warnings = warnings;
+        Ok(next)
     }
 }
 
 impl Default for Accumulator {
     fn default() -> Self {
-        Accumulator(Some(vec![]))
+        Accumulator {
+            errors: Some(vec![]),
+            warnings: vec![],
+        }
     }
 }
 
@@ -950,7 +1075,7 @@ impl Drop for Accumulator {
         // don't try to panic if we are currently unwinding a panic
         // otherwise we end up with an unhelful "thread panicked while panicking. aborting." message
         if !std::thread::panicking() {
-            if let Some(errors) = &mut self.0 {
+            if let Some(errors) = &mut self.errors {
                 match errors.len() {
                     0 => panic!("darling::error::Accumulator dropped without being finished"),
                     error_count => panic!("darling::error::Accumulator dropped without being finished. {} errors were lost.", error_count)
@@ -962,7 +1087,7 @@ impl Drop for Accumulator {
 
 #[cfg(test)]
 mod tests {
-    use super::Error;
+    use super::{Error, Warning};
 
     #[test]
     fn flatten_noop() {
@@ -995,6 +1120,21 @@ mod tests {
         assert!(err_iter.next().is_none());
     }
 
+    #[test]
+    fn span_at_location() {
+        let span = proc_macro2::Span::call_site();
+        let err = Error::multiple(vec![
+            Error::custom("too low").at("port"),
+            Error::custom("unrelated").at("host"),
+            Error::multiple(vec![Error::custom("nested"), Error::custom("also")]).at("port"),
+            Error::custom("no location"),
+        ])
+        .with_span_at("port", &span);
+
+        let spanned: Vec<_> = err.flatten().into_iter().map(|e| e.has_span()).collect();
+        assert_eq!(spanned, vec![true, false, true, true, false]);
+    }
+
     #[test]
     fn len_single() {
         let err = Error::duplicate_field("hello");
@@ -1066,6 +1206,26 @@ mod tests {
         errs.checkpoint().unwrap_err();
     }
 
+    #[test]
+    fn accum_warnings() {
+        let mut errs = Error::accumulator();
+        errs.warn(Warning::new("careful"));
+        errs = errs.checkpoint().unwrap();
+        let ((), warnings) = errs.finish_with_warnings(()).unwrap();
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn accum_warnings_across_checkpoint() {
+        let mut errs = Error::accumulator();
+        errs.warn(Warning::new("careful"));
+        errs = errs.checkpoint().unwrap();
+        errs.warn(Warning::new("again"));
+
+        let ((), warnings) = errs.finish_with_warnings(()).unwrap();
+        assert_eq!(warnings.len(), 2);
+    }
+
     #[test]
     #[should_panic(expected = "Accumulator dropped")]
     fn accum_checkpoint_drop_panic() {
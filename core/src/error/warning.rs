@@ -0,0 +1,116 @@
+use std::fmt;
+
+use proc_macro2::{Span, TokenStream};
+use syn::spanned::Spanned;
+
+/// A non-fatal problem encountered during attribute parsing.
+///
+/// Unlike [`Error`](super::Error), a `Warning` does not prevent parsing from producing a value.
+/// Derived impls report warnings for things like the use of `#[darling(deprecated)]` fields.
+///
+/// # Usage
+/// Derived impls return their warnings in a field marked `#[darling(warnings)]`, and pass them to
+/// the caller of [`FromMeta::from_meta_with_warnings`](crate::FromMeta::from_meta_with_warnings).
+/// Hand-written code can use [`Accumulator::warn`](super::Accumulator::warn) to collect them
+/// alongside errors. Then return [`Warning::write_warning`] from the proc-macro to show them to
+/// the user.
+///
+/// ```rust
+/// # extern crate darling_core as darling;
+/// use darling::error::Warning;
+///
+/// let mut errors = darling::Error::accumulator();
+/// errors.warn(Warning::new("`old` is deprecated"));
+///
+/// let (value, warnings) = errors.finish_with_warnings(5).unwrap();
+/// assert_eq!(value, 5);
+/// assert_eq!(warnings.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Warning {
+    message: String,
+    /// The span to highlight in the emitted diagnostic.
+    span: Option<Span>,
+}
+
+impl Warning {
+    /// Creates a new warning with a custom message.
+    pub fn new<T: fmt::Display>(message: T) -> Self {
+        Warning {
+            message: message.to_string(),
+            span: None,
+        }
+    }
+
+    /// Tie a span to the warning if none is already present.
+    pub fn with_span<T: Spanned>(mut self, node: &T) -> Self {
+        if self.span.is_none() {
+            self.span = Some(node.span());
+        }
+
+        self
+    }
+
+    /// Get a span for the warning.
+    ///
+    /// This returns [`Span::call_site()`] if no span has been set.
+    pub fn span(&self) -> Span {
+        self.span.unwrap_or_else(Span::call_site)
+    }
+
+    /// Get the span for `self`, if one has been set.
+    pub fn explicit_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Get the warning message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Write this warning into a `TokenStream` to be returned by the proc-macro.
+    ///
+    /// On stable Rust, this produces a `#[deprecated]` constant and a use of that constant, spanned
+    /// on the warning's location, which causes the compiler to show the message as a warning.
+    ///
+    /// If the `diagnostics` feature is enabled, the warning is instead emitted immediately as a
+    /// compiler diagnostic, and an empty `TokenStream` is returned.
+    ///
+    /// # Usage
+    /// ```rust,ignore
+    /// // in your proc-macro function, where `MyOptions` has a `#[darling(warnings)]` field
+    /// let opts = match MyOptions::from_derive_input(&ast) {
+    ///     Ok(val) => val,
+    ///     Err(err) => return err.write_errors().into(),
+    /// };
+    /// let mut output = expand(&opts);
+    /// output.extend(opts.warnings.into_iter().map(Warning::write_warning));
+    /// ```
+    pub fn write_warning(self) -> TokenStream {
+        #[cfg(feature = "diagnostics")]
+        {
+            self.span().unwrap().warning(self.message).emit();
+            TokenStream::default()
+        }
+
+        #[cfg(not(feature = "diagnostics"))]
+        {
+            let message = &self.message;
+            let usage = quote::quote_spanned!(self.span()=> warning);
+            quote::quote! {
+                const _: () = {
+                    #[deprecated(note = #message)]
+                    #[allow(non_upper_case_globals)]
+                    const warning: () = ();
+                    let _ = #usage;
+                };
+            }
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
@@ -12,6 +12,7 @@ use std::sync::Arc;
 use syn::{Expr, Ident, Lit, Meta, Path};
 
 use crate::ast::NestedMeta;
+use crate::error::Warning;
 use crate::util::path_to_string;
 use crate::{Error, Result};
 
@@ -78,6 +79,24 @@ pub trait FromMeta: Sized {
         .map_err(|e| e.with_span(item))
     }
 
+    /// Create an instance like [`from_meta`](Self::from_meta), adding any warnings about the
+    /// input, such as the use of a `#[darling(deprecated)]` field, to `warnings`.
+    ///
+    /// The default implementation calls `from_meta` and adds no warnings. Derived impls override
+    /// this, and read their own fields with it so that the warnings of nested values reach the
+    /// caller too.
+    fn from_meta_with_warnings(item: &Meta, warnings: &mut Vec<Warning>) -> Result<Self> {
+        let _ = warnings;
+        Self::from_meta(item)
+    }
+
+    /// Create an instance like [`from_list`](Self::from_list), adding any warnings about the
+    /// input to `warnings`. The default implementation calls `from_list` and adds no warnings.
+    fn from_list_with_warnings(items: &[NestedMeta], warnings: &mut Vec<Warning>) -> Result<Self> {
+        let _ = warnings;
+        Self::from_list(items)
+    }
+
     /// When a field is omitted from a parent meta-item, `from_none` is used to attempt
     /// recovery before a missing field error is generated.
     ///
@@ -92,6 +111,47 @@ pub trait FromMeta: Sized {
         None
     }
 
+    /// The keys this type accepts when parsed from a list, or `None` if they aren't known.
+    ///
+    /// When a struct has several `#[darling(flatten)]` fields, each unclaimed item is given to
+    /// the flattened field whose type accepts its key. Types which return `None`, the default,
+    /// receive the items that no other flattened field accepts.
+    ///
+    /// Derived impls for structs with named fields return their field names and aliases.
+    fn field_names() -> Option<Vec<String>> {
+        None
+    }
+
+    /// Whether `name` is one of the keys this type accepts when parsed from a list.
+    ///
+    /// Several `#[darling(flatten)]` fields use this to decide which of them receives an item.
+    /// The default implementation looks for `name` in [`field_names`](Self::field_names). Derived
+    /// impls match `name` the way parsing does, so `case_insensitive` fields ignore its case.
+    fn accepts_field(name: &str) -> bool {
+        Self::field_names().map_or(false, |names| names.iter().any(|known| known == name))
+    }
+
+    /// Create an instance from the items of a parent's list which were given to this type's
+    /// `#[darling(flatten)]` field.
+    ///
+    /// The default implementation calls `from_list`. Derived impls for enums override this so
+    /// that the variant is selected by whichever variant key appears among the items, with an
+    /// error if none or more than one of them does.
+    fn from_flattened(items: &[NestedMeta]) -> Result<Self> {
+        Self::from_list(items)
+    }
+
+    /// Combine a repeated occurrence `other` of a list into `target`, for a field with
+    /// `#[darling(on_duplicate = "merge")]`. The merged list is parsed once every occurrence
+    /// has been seen.
+    ///
+    /// The default implementation appends the items of `other` with [`crate::util::merge_meta`].
+    /// Derived impls for structs with named fields merge the occurrences of a nested list field
+    /// key by key, and append the items of `multiple` fields.
+    fn merge_duplicate(target: &mut Meta, other: &Meta) -> Result<()> {
+        crate::util::merge_meta(target, other)
+    }
+
     /// Create an instance from the presence of the word in the attribute with no
     /// additional options specified.
     fn from_word() -> Result<Self> {
@@ -663,9 +723,35 @@ impl<T: FromMeta> FromMeta for Option<T> {
         Some(None)
     }
 
+    fn field_names() -> Option<Vec<String>> {
+        T::field_names()
+    }
+
+    fn accepts_field(name: &str) -> bool {
+        T::accepts_field(name)
+    }
+
+    /// A flattened optional value is absent when none of the parent's items were given to it,
+    /// such as a flattened enum whose variant keys don't appear.
+    fn from_flattened(items: &[NestedMeta]) -> Result<Self> {
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        T::from_flattened(items).map(Some)
+    }
+
+    fn merge_duplicate(target: &mut Meta, other: &Meta) -> Result<()> {
+        T::merge_duplicate(target, other)
+    }
+
     fn from_meta(item: &Meta) -> Result<Self> {
         FromMeta::from_meta(item).map(Some)
     }
+
+    fn from_meta_with_warnings(item: &Meta, warnings: &mut Vec<Warning>) -> Result<Self> {
+        T::from_meta_with_warnings(item, warnings).map(Some)
+    }
 }
 
 impl<T: FromMeta> FromMeta for Result<T> {
@@ -680,9 +766,17 @@ impl<T: FromMeta> FromMeta for Result<T> {
         Ok(FromMeta::from_list(items))
     }
 
+    fn merge_duplicate(target: &mut Meta, other: &Meta) -> Result<()> {
+        T::merge_duplicate(target, other)
+    }
+
     fn from_meta(item: &Meta) -> Result<Self> {
         Ok(FromMeta::from_meta(item))
     }
+
+    fn from_meta_with_warnings(item: &Meta, warnings: &mut Vec<Warning>) -> Result<Self> {
+        Ok(T::from_meta_with_warnings(item, warnings))
+    }
 }
 
 /// Create an impl that forwards to an inner type `T` for parsing.
@@ -700,9 +794,36 @@ macro_rules! smart_pointer_t {
                 FromMeta::from_list(items).map($map_fn)
             }
 
+            fn field_names() -> Option<Vec<String>> {
+                T::field_names()
+            }
+
+            fn accepts_field(name: &str) -> bool {
+                T::accepts_field(name)
+            }
+
+            fn from_flattened(items: &[NestedMeta]) -> Result<Self> {
+                T::from_flattened(items).map($map_fn)
+            }
+
+            fn merge_duplicate(target: &mut Meta, other: &Meta) -> Result<()> {
+                T::merge_duplicate(target, other)
+            }
+
             fn from_meta(item: &Meta) -> Result<Self> {
                 FromMeta::from_meta(item).map($map_fn)
             }
+
+            fn from_meta_with_warnings(item: &Meta, warnings: &mut Vec<Warning>) -> Result<Self> {
+                T::from_meta_with_warnings(item, warnings).map($map_fn)
+            }
+
+            fn from_list_with_warnings(
+                items: &[NestedMeta],
+                warnings: &mut Vec<Warning>,
+            ) -> Result<Self> {
+                T::from_list_with_warnings(items, warnings).map($map_fn)
+            }
         }
     };
 }
@@ -911,6 +1032,8 @@ map!(btree_map, syn::Ident, nested);
 #[allow(clippy::wrong_self_convention)]
 pub mod autoref_specialization {
     use super::FromMeta;
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{BuildHasher, Hash};
     use std::marker::PhantomData;
 
     pub struct FromMetaTag<T>(PhantomData<T>);
@@ -946,6 +1069,61 @@ pub mod autoref_specialization {
     impl<T> SpecFromMetaAll<T> for &&PhantomData<T> {}
 
     impl<T: FromMeta> SpecFromMeta<T> for &PhantomData<T> {}
+
+    /// Adds values to the collection of a `#[darling(multiple)]` field, reporting whether
+    /// the value was new.
+    ///
+    /// Sets select [`SetInsertTag`], which uses the set's own `insert` so that repeated
+    /// values can be reported. Any other `Default + Extend` collection selects [`ExtendTag`],
+    /// which always accepts the value.
+    ///
+    /// ```ignore
+    /// use _darling::autoref_specialization::{
+    ///     SpecSetInsert as _,
+    ///     SpecExtendAll as _
+    /// };
+    ///
+    /// let is_new = (&_darling::export::PhantomData::<C>).collect_tag().insert(&mut collection, value);
+    /// ```
+    pub struct SetInsertTag<C>(PhantomData<C>);
+    pub struct ExtendTag<C>(PhantomData<C>);
+
+    impl<T: Ord> SetInsertTag<BTreeSet<T>> {
+        pub fn insert(self, collection: &mut BTreeSet<T>, value: T) -> bool {
+            collection.insert(value)
+        }
+    }
+
+    impl<T: Hash + Eq, S: BuildHasher> SetInsertTag<HashSet<T, S>> {
+        pub fn insert(self, collection: &mut HashSet<T, S>, value: T) -> bool {
+            collection.insert(value)
+        }
+    }
+
+    impl<C: IntoIterator + Extend<C::Item>> ExtendTag<C> {
+        pub fn insert(self, collection: &mut C, value: C::Item) -> bool {
+            collection.extend(Some(value));
+            true
+        }
+    }
+
+    pub trait SpecSetInsert<C>: Sized {
+        fn collect_tag(self) -> SetInsertTag<C> {
+            SetInsertTag(PhantomData)
+        }
+    }
+
+    pub trait SpecExtendAll<C>: Sized {
+        fn collect_tag(self) -> ExtendTag<C> {
+            ExtendTag(PhantomData)
+        }
+    }
+
+    impl<C> SpecExtendAll<C> for &&PhantomData<C> {}
+
+    impl<T: Ord> SpecSetInsert<BTreeSet<T>> for &PhantomData<BTreeSet<T>> {}
+
+    impl<T: Hash + Eq, S: BuildHasher> SpecSetInsert<HashSet<T, S>> for &PhantomData<HashSet<T, S>> {}
 }
 
 impl FromMeta for Vec<Ident> {
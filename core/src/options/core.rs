@@ -4,8 +4,10 @@ use crate::ast::{Data, Fields, Style};
 use crate::codegen;
 use crate::codegen::PostfixTransform;
 use crate::error::Accumulator;
-use crate::options::{DefaultExpression, InputField, InputVariant, ParseAttribute, ParseData};
-use crate::util::Flag;
+use crate::options::{
+    DefaultExpression, InputField, InputVariant, OnDuplicate, ParseAttribute, ParseData, RenameAll,
+};
+use crate::util::{names_match, Callable, Flag, PathList, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 /// A struct or enum which should have `FromMeta` or `FromDeriveInput` implementations
@@ -24,7 +26,11 @@ pub struct Core {
     pub default: Option<DefaultExpression>,
 
     /// The rule that should be used to rename all fields/variants in the container.
-    pub rename_rule: RenameRule,
+    pub rename_rule: RenameAll,
+
+    /// The rule used to rename the fields of an enum's variants, if it differs from the rule
+    /// for the variants themselves.
+    pub rename_all_fields: Option<SpannedValue<RenameAll>>,
 
     /// The crate name to use for darling
     pub krate: Option<syn::Path>,
@@ -37,6 +43,10 @@ pub struct Core {
     /// which the two are applied.
     pub post_transform: Option<codegen::PostfixTransform>,
 
+    /// A function which checks the finished value, from `#[darling(validate = ...)]`. It is an
+    /// `Fn(&T, &mut darling::error::Accumulator)` that reports every problem it finds.
+    pub validate: Option<Callable>,
+
     /// The body of the _deriving_ type.
     pub data: Data<InputVariant, InputField>,
 
@@ -48,6 +58,18 @@ pub struct Core {
 
     /// Use implementation of the inner type
     pub transparent: Flag,
+
+    /// Whether field keys and variant names match the input regardless of case, `_`, and `-`.
+    /// This can be overridden at the field level.
+    pub case_insensitive: Flag,
+
+    /// Groups of fields where exactly one field from each group must be present in the input,
+    /// from `#[darling(one_of(a, b, c))]`.
+    pub one_of: Vec<Vec<syn::Ident>>,
+
+    /// How fields handle their key appearing more than once in the input, unless the field
+    /// sets its own policy.
+    pub on_duplicate: Option<SpannedValue<OnDuplicate>>,
 }
 
 impl Core {
@@ -62,17 +84,29 @@ impl Core {
             // See https://github.com/TedDriggs/darling/issues/10: We default to snake_case
             // for enums to help authors produce more idiomatic APIs.
             rename_rule: if let syn::Data::Enum(_) = di.data {
-                RenameRule::SnakeCase
+                RenameRule::SnakeCase.into()
             } else {
                 Default::default()
             },
+            rename_all_fields: Default::default(),
             post_transform: Default::default(),
+            validate: Default::default(),
             bound: Default::default(),
             allow_unknown_fields: Default::default(),
             transparent: Default::default(),
+            case_insensitive: Default::default(),
+            one_of: Default::default(),
+            on_duplicate: Default::default(),
         })
     }
 
+    /// The rule used to name the fields of the enum's variants, unless a variant sets its own.
+    pub fn variant_field_rename_rule(&self) -> &RenameAll {
+        self.rename_all_fields
+            .as_deref()
+            .unwrap_or(&self.rename_rule)
+    }
+
     fn as_codegen_default(&self) -> Option<codegen::DefaultExpression<'_>> {
         self.default.as_ref().map(|expr| match *expr {
             DefaultExpression::Explicit(ref callable) => {
@@ -102,7 +136,14 @@ impl ParseAttribute for Core {
         } else if path.is_ident("rename_all") {
             // WARNING: This may have been set based on body shape previously,
             // so an overwrite may be permissible.
-            self.rename_rule = FromMeta::from_meta(mi)?;
+            let rename_rule: RenameAll = FromMeta::from_meta(mi)?;
+            self.rename_rule = rename_rule.or_case_of(&self.rename_rule);
+        } else if path.is_ident("rename_all_fields") {
+            if self.rename_all_fields.is_some() {
+                return Err(Error::duplicate_field("rename_all_fields").with_span(mi));
+            }
+
+            self.rename_all_fields = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("map") || path.is_ident("and_then") {
             // This unwrap is safe because we just called is_ident above
             let transformer = path.get_ident().unwrap().clone();
@@ -121,6 +162,12 @@ impl ParseAttribute for Core {
 
             self.post_transform =
                 Some(PostfixTransform::new(transformer, FromMeta::from_meta(mi)?));
+        } else if path.is_ident("validate") {
+            if self.validate.is_some() {
+                return Err(Error::duplicate_field("validate").with_span(mi));
+            }
+
+            self.validate = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("bound") {
             self.bound = FromMeta::from_meta(mi)?;
         } else if path.is_ident("allow_unknown_fields") {
@@ -135,6 +182,33 @@ impl ParseAttribute for Core {
             }
 
             self.transparent = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("case_insensitive") {
+            if self.case_insensitive.is_present() {
+                return Err(Error::duplicate_field("case_insensitive").with_span(mi));
+            }
+
+            self.case_insensitive = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("one_of") {
+            let members = PathList::from_meta(mi)?
+                .iter()
+                .map(|path| {
+                    path.get_ident()
+                        .cloned()
+                        .ok_or_else(|| Error::custom("Expected a field name").with_span(path))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            if members.len() < 2 {
+                return Err(Error::custom("`one_of` requires at least two fields").with_span(mi));
+            }
+
+            self.one_of.push(members);
+        } else if path.is_ident("on_duplicate") {
+            if self.on_duplicate.is_some() {
+                return Err(Error::duplicate_field("on_duplicate").with_span(mi));
+            }
+
+            self.on_duplicate = FromMeta::from_meta(mi)?;
         } else if path.is_ident("crate") {
             if self.krate.is_some() {
                 return Err(Error::duplicate_field("crate").with_span(mi));
@@ -178,6 +252,58 @@ impl ParseData for Core {
     }
 
     fn validate_body(&self, errors: &mut Accumulator) {
+        match &self.data {
+            Data::Struct(fields) => {
+                validate_tuple_fields(fields, errors);
+                validate_rest(&fields.fields, errors);
+                validate_positional(&fields.fields, errors);
+                validate_constraints(&fields.fields, &self.one_of, errors);
+                validate_default_from(&fields.fields, errors);
+                validate_case_insensitive(fields_input_names(&fields.fields), errors);
+
+                if let Some(rename_all_fields) = &self.rename_all_fields {
+                    errors.push(
+                        Error::custom("`rename_all_fields` can only be used on enums")
+                            .with_span(&rename_all_fields.span()),
+                    );
+                }
+            }
+            Data::Enum(variants) => {
+                for variant in variants {
+                    validate_tuple_fields(variant.fields(), errors);
+                    validate_rest(&variant.fields().fields, errors);
+                    validate_positional(&variant.fields().fields, errors);
+                    validate_constraints(&variant.fields().fields, &[], errors);
+                    validate_default_from(&variant.fields().fields, errors);
+                    validate_case_insensitive(fields_input_names(&variant.fields().fields), errors);
+                }
+
+                validate_case_insensitive(
+                    variants
+                        .iter()
+                        .map(|variant| {
+                            let (names, case_insensitive) = variant.input_names();
+                            (&variant.ident, names, case_insensitive)
+                        })
+                        .collect(),
+                    errors,
+                );
+
+                for group in &self.one_of {
+                    errors.push(
+                        Error::custom("`one_of` can only be used on structs").with_span(&group[0]),
+                    );
+                }
+
+                if let Some(validate) = &self.validate {
+                    errors.push(
+                        Error::custom("`validate` can only be used on structs")
+                            .with_span(validate.as_ref()),
+                    );
+                }
+            }
+        }
+
         if let Data::Struct(fields) = &self.data {
             if self.transparent.is_present() && fields.len() != 1 {
                 errors.push(
@@ -185,23 +311,161 @@ impl ParseData for Core {
                         .with_span(&self.transparent.span()),
                 );
             }
+        }
+    }
+}
 
-            let flatten_targets: Vec<_> = fields
+/// Check that tuple fields, which are always read by position, don't use options that only
+/// make sense for named fields.
+fn validate_tuple_fields(fields: &Fields<InputField>, errors: &mut Accumulator) {
+    if !fields.is_tuple() {
+        return;
+    }
+
+    for field in fields.iter() {
+        if field.flatten.is_present() {
+            errors.push(
+                Error::custom("`flatten` cannot be used on tuple fields")
+                    .with_span(&field.flatten.span()),
+            );
+        }
+
+        if field.rest.is_present() {
+            errors.push(
+                Error::custom("`rest` cannot be used on tuple fields")
+                    .with_span(&field.rest.span()),
+            );
+        }
+
+        if field.warnings.is_present() {
+            errors.push(
+                Error::custom("`warnings` cannot be used on tuple fields")
+                    .with_span(&field.warnings.span()),
+            );
+        }
+
+        if field.positional.is_present() {
+            errors.push(
+                Error::custom("Tuple fields are always positional")
+                    .with_span(&field.positional.span()),
+            );
+        }
+
+        if field.multiple.unwrap_or_default() {
+            errors.push(
+                Error::custom("`multiple` cannot be used on tuple fields").with_span(&field.ty),
+            );
+        }
+
+        if let Some(default_from) = &field.default_from {
+            errors.push(
+                Error::custom("`default_from` cannot be used on tuple fields")
+                    .with_span(&default_from.with),
+            );
+        }
+    }
+}
+
+/// Check that at most one field collects the unclaimed items, and that it isn't competing
+/// with a `flatten` field for them.
+fn validate_rest(fields: &[InputField], errors: &mut Accumulator) {
+    let rest_fields: Vec<_> = fields.iter().filter(|f| f.rest.is_present()).collect();
+
+    if rest_fields.len() > 1 {
+        for field in &rest_fields {
+            errors.push(
+                Error::custom("`#[darling(rest)]` can only be applied to one field")
+                    .with_span(&field.rest.span()),
+            );
+        }
+    }
+
+    if !rest_fields.is_empty() {
+        for field in fields.iter().filter(|f| f.flatten.is_present()) {
+            errors.push(
+                Error::custom("`flatten` cannot be used in a struct with a `rest` field")
+                    .with_span(&field.flatten.span()),
+            );
+        }
+    }
+}
+
+/// Check that positional fields take a single value and come before all fields which can only
+/// be set by name, since positional arguments are assigned to fields in declaration order.
+fn validate_positional(fields: &[InputField], errors: &mut Accumulator) {
+    let mut named_seen = false;
+
+    for field in fields {
+        if !field.positional.is_present() {
+            named_seen |= field.is_addressable();
+            continue;
+        }
+
+        let span = field.positional.span();
+
+        if !field.is_addressable() {
+            errors.push(
+                Error::custom("`positional` cannot be used on skipped or flattened fields")
+                    .with_span(&span),
+            );
+        }
+
+        if field.multiple.unwrap_or_default() {
+            errors.push(
+                Error::custom("`positional` and `multiple` cannot be used together")
+                    .with_span(&span),
+            );
+        }
+
+        if named_seen {
+            errors.push(
+                Error::custom("Positional fields must come before all other fields")
+                    .with_span(&span),
+            );
+        }
+    }
+}
+
+/// The names which set each field in the input, and whether they match regardless of case.
+fn fields_input_names(fields: &[InputField]) -> Vec<(&syn::Ident, Vec<&str>, bool)> {
+    fields
+        .iter()
+        .filter(|field| field.is_addressable())
+        .map(|field| {
+            // A name computed by `rename_all = some_fn` is only known when parsing.
+            let names = field
+                .attr_name
                 .iter()
-                .filter_map(|field| {
-                    if field.flatten.is_present() {
-                        Some(field.flatten)
-                    } else {
-                        None
-                    }
-                })
+                .filter(|_| field.rename_with.is_none())
+                .chain(&field.aliases)
+                .map(String::as_str)
                 .collect();
+            (&field.ident, names, field.case_insensitive.is_present())
+        })
+        .collect()
+}
 
-            if flatten_targets.len() > 1 {
-                for flatten in flatten_targets {
+/// Check that the input can tell siblings apart when some of their names match regardless of
+/// case, `_`, and `-`. Names computed by a `rename_all` function can't be checked here, so
+/// `siblings` only has the names which are known when deriving.
+fn validate_case_insensitive(
+    siblings: Vec<(&syn::Ident, Vec<&str>, bool)>,
+    errors: &mut Accumulator,
+) {
+    for (index, (ident, names, case_insensitive)) in siblings.iter().enumerate() {
+        for (_, earlier_names, earlier_case_insensitive) in &siblings[..index] {
+            if !case_insensitive && !earlier_case_insensitive {
+                continue;
+            }
+
+            for name in names {
+                if let Some(earlier) = earlier_names.iter().find(|e| names_match(name, e)) {
                     errors.push(
-                        Error::custom("`#[darling(flatten)]` can only be applied to one field")
-                            .with_span(&flatten.span()),
+                        Error::custom(format!(
+                            "`{}` and `{}` are the same name when matched case-insensitively",
+                            earlier, name
+                        ))
+                        .with_span(*ident),
                     );
                 }
             }
@@ -209,6 +473,153 @@ impl ParseData for Core {
     }
 }
 
+/// Check that every field named by `conflicts_with`, `requires`, or `one_of` is a sibling
+/// which can appear in the input.
+fn validate_constraints(
+    fields: &[InputField],
+    one_of: &[Vec<syn::Ident>],
+    errors: &mut Accumulator,
+) {
+    let names = fields
+        .iter()
+        .filter(|f| f.is_addressable())
+        .map(|f| f.ident.to_string())
+        .collect::<Vec<_>>();
+
+    let check = |option: &str, subject: Option<&InputField>, target: &syn::Ident| {
+        if matches!(subject, Some(s) if s.ident == *target) {
+            Some(
+                Error::custom(format!("`{}` cannot refer to the field it is on", option))
+                    .with_span(target),
+            )
+        } else if !names.contains(&target.to_string()) {
+            Some(
+                Error::unknown_field_with_alts(&target.to_string(), &names)
+                    .with_span(target)
+                    .at(option),
+            )
+        } else {
+            None
+        }
+    };
+
+    for field in fields {
+        for target in &field.conflicts_with {
+            errors.extend(check("conflicts_with", Some(field), target));
+        }
+
+        for target in &field.requires {
+            errors.extend(check("requires", Some(field), target));
+        }
+
+        if !field.is_addressable() {
+            if let Some(target) = field.conflicts_with.iter().chain(&field.requires).next() {
+                errors.push(
+                    Error::custom(
+                        "`conflicts_with` and `requires` cannot be used on skipped or flattened fields",
+                    )
+                    .with_span(target),
+                );
+            }
+        }
+    }
+
+    for member in one_of.iter().flatten() {
+        errors.extend(check("one_of", None, member));
+    }
+}
+
+/// Check that every field read by `default_from` is a sibling holding a single value, and that
+/// no field's computed default depends on itself.
+fn validate_default_from(fields: &[InputField], errors: &mut Accumulator) {
+    let names = fields
+        .iter()
+        .map(|f| f.ident.to_string())
+        .collect::<Vec<_>>();
+    let find = |ident: &syn::Ident| fields.iter().find(|f| f.ident == *ident);
+
+    for field in fields {
+        let default_from = match &field.default_from {
+            Some(default_from) => default_from,
+            None => continue,
+        };
+
+        for target in &default_from.fields {
+            match find(target) {
+                None => errors.push(
+                    Error::unknown_field_with_alts(&target.to_string(), &names)
+                        .with_span(target)
+                        .at("default_from"),
+                ),
+                Some(dependency) if dependency.multiple.unwrap_or_default() => errors.push(
+                    Error::custom(format!(
+                        "`default_from` cannot read `multiple` field `{}`",
+                        target
+                    ))
+                    .with_span(target),
+                ),
+                Some(_) => {}
+            }
+        }
+
+        // Only fields on a cycle report it, so fields which merely depend on a cycle
+        // don't repeat the error.
+        if let Some(cycle) = shortest_cycle(fields, field) {
+            let cycle = cycle
+                .iter()
+                .map(|ident| format!("`{}`", ident))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            errors.push(
+                Error::custom(format!("Cyclic `default_from` dependency: {}", cycle))
+                    .with_span(&default_from.with),
+            );
+        }
+    }
+}
+
+/// Find the shortest chain of `default_from` dependencies which leads from `start` back to
+/// itself, returned as the fields visited with `start` at both ends.
+fn shortest_cycle<'a>(
+    fields: &'a [InputField],
+    start: &'a InputField,
+) -> Option<Vec<&'a syn::Ident>> {
+    // Each visited field with the field whose dependency it is, for walking the path back.
+    let mut visited: Vec<(&syn::Ident, Option<&syn::Ident>)> = vec![(&start.ident, None)];
+    let mut queue = std::collections::VecDeque::from([start]);
+
+    while let Some(field) = queue.pop_front() {
+        let dependencies = field.default_from.iter().flat_map(|d| &d.fields);
+        for dependency in dependencies {
+            if *dependency == start.ident {
+                let mut cycle = vec![&start.ident, &field.ident];
+                while let Some((_, Some(parent))) = visited
+                    .iter()
+                    .find(|(ident, _)| *ident == *cycle.last().unwrap())
+                {
+                    cycle.push(parent);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+
+            if visited.iter().any(|(ident, _)| *ident == dependency) {
+                continue;
+            }
+
+            if let Some(next) = fields
+                .iter()
+                .find(|f| f.ident == *dependency && f.default_from.is_some())
+            {
+                visited.push((&next.ident, Some(&field.ident)));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
 impl<'a> From<&'a Core> for codegen::TraitImpl<'a> {
     fn from(v: &'a Core) -> Self {
         codegen::TraitImpl {
@@ -218,12 +629,220 @@ impl<'a> From<&'a Core> for codegen::TraitImpl<'a> {
                 .data
                 .as_ref()
                 .map_struct_fields(InputField::as_codegen_field)
+                .map_struct(|mut fields| {
+                    codegen::track_constrained_spans(&mut fields.fields, &v.one_of);
+                    if v.validate.is_some() {
+                        codegen::track_validated_spans(&mut fields, v.transparent.is_present());
+                    }
+                    fields
+                })
                 .map_enum_variants(|variant| variant.as_codegen_variant(&v.ident)),
             default: v.as_codegen_default(),
             post_transform: v.post_transform.as_ref(),
+            validate: v.validate.as_ref().map(AsRef::as_ref),
             allow_unknown_fields: v.allow_unknown_fields.unwrap_or_default(),
             transparent: v.transparent.is_present(),
             krate: v.krate.as_ref(),
+            one_of: &v.one_of,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use crate::options::FromMetaOptions;
+
+    fn errors(input: syn::DeriveInput) -> Vec<String> {
+        match FromMetaOptions::new(&input) {
+            Ok(_) => panic!("expected errors"),
+            Err(err) => err,
+        }
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn constraint_targets() {
+        let errors = errors(parse_quote! {
+            #[darling(one_of(host, hots))]
+            struct Demo {
+                #[darling(conflicts_with = "port")]
+                port: Option<u16>,
+                #[darling(requires = "prot")]
+                host: Option<String>,
+            }
+        });
+
+        // The rest of an unknown field error depends on whether `suggestions` is enabled.
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors[0],
+            "`conflicts_with` cannot refer to the field it is on"
+        );
+        assert!(errors[1].starts_with("Unknown field: `prot`."));
+        assert!(errors[1].ends_with(" at requires"));
+        assert!(errors[2].starts_with("Unknown field: `hots`."));
+        assert!(errors[2].ends_with(" at one_of"));
+    }
+
+    #[test]
+    fn constraint_on_skipped_or_flattened_field() {
+        let errors = errors(parse_quote! {
+            struct Demo {
+                #[darling(skip, requires = "port")]
+                host: Option<String>,
+                #[darling(flatten, conflicts_with = "port")]
+                other: Other,
+                port: Option<u16>,
+                #[darling(conflicts_with = "host")]
+                timeout: Option<u32>,
+            }
+        });
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors[0],
+            "`conflicts_with` and `requires` cannot be used on skipped or flattened fields"
+        );
+        assert_eq!(errors[1], errors[0]);
+        assert!(errors[2].starts_with("Unknown field: `host`."));
+    }
+
+    #[test]
+    fn default_from_cycle() {
+        let errors = errors(parse_quote! {
+            struct Demo {
+                #[darling(default_from(b, with = |b: &u8| *b))]
+                a: u8,
+                #[darling(default_from(a, with = |a: &u8| *a))]
+                b: u8,
+                #[darling(default_from(a, with = |a: &u8| *a))]
+                c: u8,
+                #[darling(default_from(d, with = |d: &u8| *d))]
+                d: u8,
+            }
+        });
+
+        assert_eq!(
+            errors,
+            vec![
+                "Cyclic `default_from` dependency: `a` -> `b` -> `a`",
+                "Cyclic `default_from` dependency: `b` -> `a` -> `b`",
+                "Cyclic `default_from` dependency: `d` -> `d`",
+            ]
+        );
+    }
+
+    #[test]
+    fn default_from_unknown_or_multiple_field() {
+        let errors = errors(parse_quote! {
+            struct Demo {
+                #[darling(multiple)]
+                items: Vec<u8>,
+                #[darling(default_from(item, items, with = |_: &u8, _: &Vec<u8>| 0))]
+                count: u8,
+            }
+        });
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("Unknown field: `item`."));
+        assert!(errors[0].ends_with("at default_from"));
+        assert_eq!(
+            errors[1],
+            "`default_from` cannot read `multiple` field `items`"
+        );
+    }
+
+    #[test]
+    fn case_insensitive_collisions() {
+        let field_errors = errors(parse_quote! {
+            #[darling(case_insensitive)]
+            struct Demo {
+                read_only: bool,
+                #[darling(rename = "readOnly")]
+                other: bool,
+            }
+        });
+
+        assert_eq!(
+            field_errors,
+            vec!["`read_only` and `readOnly` are the same name when matched case-insensitively"]
+        );
+
+        let variant_errors = errors(parse_quote! {
+            enum Mode {
+                #[darling(case_insensitive)]
+                ReadOnly,
+                #[darling(rename = "readonly")]
+                Other,
+            }
+        });
+
+        assert_eq!(variant_errors.len(), 1);
+    }
+
+    #[test]
+    fn case_insensitive_computed_names() {
+        // `read_only` is named by calling `joined` when parsing, so it can't be compared here.
+        let input = parse_quote! {
+            #[darling(case_insensitive, rename_all = joined)]
+            struct Demo {
+                read_only: bool,
+                #[darling(rename = "ReadOnly")]
+                other: bool,
+            }
+        };
+
+        assert!(FromMetaOptions::new(&input).is_ok());
+    }
+
+    #[test]
+    fn warnings_field_misuse() {
+        let struct_errors = errors(parse_quote! {
+            struct Demo {
+                #[darling(warnings, rename = "notes")]
+                warnings: Vec<Warning>,
+                #[darling(warnings, default)]
+                defaulted: Vec<Warning>,
+                #[darling(rest, warnings)]
+                rest: Vec<NestedMeta>,
+            }
+        });
+
+        assert_eq!(
+            struct_errors,
+            vec![
+                "`warnings` and `rename` cannot be used together",
+                "`warnings` and `default` cannot be used together",
+                "`rest` and `warnings` cannot be used together",
+            ]
+        );
+
+        let tuple_errors = errors(parse_quote! {
+            struct Demo(#[darling(warnings)] Vec<Warning>);
+        });
+
+        assert_eq!(
+            tuple_errors,
+            vec!["`warnings` cannot be used on tuple fields"]
+        );
+    }
+
+    #[test]
+    fn rename_all_fields_on_struct() {
+        let errors = errors(parse_quote! {
+            #[darling(rename_all_fields = "camelCase")]
+            struct Demo {
+                field_name: bool,
+            }
+        });
+
+        assert_eq!(
+            errors,
+            vec!["`rename_all_fields` can only be used on enums"]
+        );
+    }
+}
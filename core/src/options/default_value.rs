@@ -0,0 +1,265 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote_spanned, spanned::Spanned, Expr, Lit, PathArguments, Type};
+
+use crate::util::{extract_option, Callable};
+use crate::{Error, FromMeta, Result};
+
+/// Build the fallback callable for `#[darling(default_value = ...)]` on a field of type `ty`, or
+/// `None` if the generated code must read the value with the field type's `FromMeta::from_expr`.
+///
+/// When the value is a literal and the field is a primitive or `String`, optionally wrapped in
+/// `Option`, the literal is read here by the type's own `FromMeta` impl, so that a bad default is
+/// reported on the attribute. Other literals are read when parsing, and any other expression is
+/// used as written.
+pub fn default_value_callable(ty: &Type, value: &Expr) -> Result<Option<Callable>> {
+    let span = value.span();
+
+    if !matches!(value, Expr::Lit(_)) {
+        return Ok(Some(parse_quote_spanned!(span=> || #value)));
+    }
+
+    let (inner, optional) = match extract_option::from_ref(ty) {
+        Ok(inner) => (inner, true),
+        Err(_) => (ty, false),
+    };
+
+    let read = match read_primitive(inner, value) {
+        Some(read) => read.map_err(|e| e.with_span(value))?,
+        None => return Ok(None),
+    };
+
+    Ok(Some(if optional {
+        parse_quote_spanned!(span=> || _darling::export::Some(#read))
+    } else {
+        parse_quote_spanned!(span=> || #read)
+    }))
+}
+
+/// Read `value` as the primitive or `String` type `ty`, and return the expression which produces
+/// it. Returns `None` if `ty` isn't one of those types.
+fn read_primitive(ty: &Type, value: &Expr) -> Option<Result<TokenStream>> {
+    let name = primitive_name(ty)?;
+
+    Some(match name.as_str() {
+        "u8" => read_unsigned::<u8>(value, &name),
+        "u16" => read_unsigned::<u16>(value, &name),
+        "u32" => read_unsigned::<u32>(value, &name),
+        "u64" => read_unsigned::<u64>(value, &name),
+        "u128" => read_unsigned::<u128>(value, &name),
+        "usize" => read_unsigned::<usize>(value, &name),
+        "i8" => read::<i8>(value),
+        "i16" => read::<i16>(value),
+        "i32" => read::<i32>(value),
+        "i64" => read::<i64>(value),
+        "i128" => read::<i128>(value),
+        "isize" => read::<isize>(value),
+        "f32" => read_float(value, &name, |v: &f32| v.is_finite()),
+        "f64" => read_float(value, &name, |v: &f64| v.is_finite()),
+        "bool" => read::<bool>(value),
+        "char" => read::<char>(value),
+        "String" => String::from_expr(value).map(|s| quote!(_darling::export::String::from(#s))),
+        _ => return None,
+    })
+}
+
+/// Get the name of `ty` if it names a primitive or `String`: either a bare identifier, or the
+/// full path of that type in `std`, `core`, or `alloc`. Other paths may name a user type which
+/// shares the name, so they are left to be read when parsing.
+fn primitive_name(ty: &Type) -> Option<String> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+
+    if path
+        .segments
+        .iter()
+        .any(|segment| !matches!(segment.arguments, PathArguments::None))
+    {
+        return None;
+    }
+
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        [name] if path.leading_colon.is_none() => Some(name.to_string()),
+        ["std" | "core", "primitive", name] if *name != "String" => Some(name.to_string()),
+        ["std" | "alloc", "string", "String"] => Some("String".into()),
+        _ => None,
+    }
+}
+
+fn read<T: FromMeta + ToTokens>(value: &Expr) -> Result<TokenStream> {
+    T::from_expr(value).map(|value| value.into_token_stream())
+}
+
+/// Read an unsigned integer, reporting a negative value as out of range rather than with the
+/// parser's message about an invalid digit. The compiler passes `-1` in attribute input as a
+/// single literal, so this can't be told apart by the shape of the expression.
+fn read_unsigned<T: FromMeta + ToTokens>(value: &Expr, name: &str) -> Result<TokenStream> {
+    let digits = match value {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => Some(int.base10_digits().to_string()),
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match digits {
+        Some(digits) if digits.trim_start().starts_with('-') => Err(Error::custom(format!(
+            "`{}` is out of range for `{}`",
+            digits.trim(),
+            name
+        ))
+        .with_span(value)),
+        _ => read::<T>(value),
+    }
+}
+
+/// Read a float, rejecting infinite and NaN values, which have no literal form.
+fn read_float<T: FromMeta + ToTokens>(
+    value: &Expr,
+    name: &str,
+    is_finite: fn(&T) -> bool,
+) -> Result<TokenStream> {
+    let read = T::from_expr(value)?;
+    if !is_finite(&read) {
+        return Err(
+            Error::custom(format!("Default value for `{}` must be finite", name)).with_span(value),
+        );
+    }
+
+    Ok(read.into_token_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::default_value_callable;
+
+    fn callable(ty: syn::Type, value: syn::Expr) -> Option<String> {
+        default_value_callable(&ty, &value)
+            .unwrap()
+            .map(|callable| callable.as_ref().to_token_stream().to_string())
+    }
+
+    #[test]
+    fn primitives() {
+        let callable = |ty, value| callable(ty, value).unwrap();
+
+        assert_eq!(
+            callable(parse_quote!(u16), parse_quote!(8080)),
+            "| | 8080u16"
+        );
+        assert_eq!(
+            callable(parse_quote!(u16), parse_quote!("8080")),
+            "| | 8080u16"
+        );
+        assert_eq!(
+            callable(parse_quote!(Option<bool>), parse_quote!("true")),
+            "| | _darling :: export :: Some (true)"
+        );
+        assert_eq!(
+            callable(parse_quote!(String), parse_quote!("localhost")),
+            "| | _darling :: export :: String :: from (\"localhost\")"
+        );
+    }
+
+    #[test]
+    fn invalid_primitives() {
+        let invalid = |ty: syn::Type, value: syn::Expr| {
+            let err = default_value_callable(&ty, &value).unwrap_err();
+            assert!(err.has_span());
+            err.to_string()
+        };
+
+        assert_eq!(
+            invalid(parse_quote!(u8), parse_quote!("x")),
+            "Unknown value: `x`"
+        );
+        assert!(invalid(parse_quote!(u8), parse_quote!(256)).contains("too large"));
+        assert!(invalid(parse_quote!(String), parse_quote!(1)) == "Unexpected type `int`");
+        assert!(invalid(parse_quote!(bool), parse_quote!("yes")).starts_with("Unknown value"));
+        assert_eq!(
+            invalid(parse_quote!(f64), parse_quote!("inf")),
+            "Default value for `f64` must be finite"
+        );
+        assert_eq!(
+            invalid(parse_quote!(f64), parse_quote!("NaN")),
+            "Default value for `f64` must be finite"
+        );
+        assert_eq!(
+            invalid(parse_quote!(f32), parse_quote!(1e400)),
+            "Default value for `f32` must be finite"
+        );
+    }
+
+    /// The compiler passes a negative number in attribute input as a single literal.
+    fn negative_literal(repr: &str) -> syn::Expr {
+        syn::Expr::Lit(syn::ExprLit {
+            attrs: vec![],
+            lit: syn::Lit::Int(syn::LitInt::new(repr, proc_macro2::Span::call_site())),
+        })
+    }
+
+    #[test]
+    fn negative_literals() {
+        let err = default_value_callable(&parse_quote!(u16), &negative_literal("-1")).unwrap_err();
+        assert!(err.has_span());
+        assert_eq!(err.to_string(), "`-1` is out of range for `u16`");
+
+        let err =
+            default_value_callable(&parse_quote!(Option<u32>), &parse_quote!("-1")).unwrap_err();
+        assert_eq!(err.to_string(), "`-1` is out of range for `u32`");
+
+        assert_eq!(
+            callable(parse_quote!(i8), negative_literal("-128")).unwrap(),
+            "| | - 128i8"
+        );
+    }
+
+    #[test]
+    fn only_std_paths_are_primitive() {
+        assert_eq!(callable(parse_quote!(my::u16), parse_quote!("x")), None);
+        assert_eq!(callable(parse_quote!(::String), parse_quote!(1)), None);
+        assert_eq!(callable(parse_quote!(crate::String), parse_quote!(1)), None);
+        assert_eq!(
+            callable(parse_quote!(std::string::String), parse_quote!("a")).unwrap(),
+            "| | _darling :: export :: String :: from (\"a\")"
+        );
+        assert_eq!(
+            callable(parse_quote!(core::primitive::u8), parse_quote!(1)).unwrap(),
+            "| | 1u8"
+        );
+    }
+
+    #[test]
+    fn other_literals_are_read_when_parsing() {
+        assert_eq!(callable(parse_quote!(Level), parse_quote!("debug")), None);
+        assert_eq!(
+            callable(parse_quote!(Option<Level>), parse_quote!("debug")),
+            None
+        );
+    }
+
+    #[test]
+    fn expressions_used_as_written() {
+        assert_eq!(
+            callable(parse_quote!(u32), parse_quote!(60 * 60)).unwrap(),
+            "| | 60 * 60"
+        );
+        assert_eq!(
+            callable(parse_quote!(u32), parse_quote!(u32::MAX)).unwrap(),
+            "| | u32 :: MAX"
+        );
+    }
+}
@@ -8,7 +8,7 @@ use crate::ast::Data;
 use crate::codegen::FromMetaImpl;
 use crate::error::Accumulator;
 use crate::options::{Core, ParseAttribute, ParseData};
-use crate::util::Callable;
+use crate::util::{Callable, Flag, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 pub struct FromMetaOptions {
@@ -21,6 +21,11 @@ pub struct FromMetaOptions {
     from_expr: Option<Callable>,
     /// Whether or not to derive [`syn::parse::Parse`] in addition to deriving [`FromMeta`].
     derive_syn_parse: Option<bool>,
+    /// The key which selects the variant of an internally-tagged enum, e.g. `kind` in
+    /// `source(kind = "file", path = "x")`.
+    tag: Option<SpannedValue<String>>,
+    /// Whether the enum should try each variant in turn against the whole input.
+    untagged: Flag,
 }
 
 impl FromMetaOptions {
@@ -31,6 +36,8 @@ impl FromMetaOptions {
             from_none: None,
             from_expr: None,
             derive_syn_parse: None,
+            tag: None,
+            untagged: Flag::default(),
         })
         .parse_attributes(&di.attrs)?
         .parse_body(&di.data)
@@ -93,6 +100,18 @@ impl ParseAttribute for FromMetaOptions {
             }
 
             self.derive_syn_parse = FromMeta::from_meta(mi).map(Some)?;
+        } else if path.is_ident("tag") {
+            if self.tag.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.tag = FromMeta::from_meta(mi).map(Some)?;
+        } else if path.is_ident("untagged") {
+            if self.untagged.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.untagged = FromMeta::from_meta(mi)?;
         } else {
             self.base.parse_nested(mi)?;
         }
@@ -115,6 +134,12 @@ impl ParseData for FromMetaOptions {
 
         match self.base.data {
             Data::Struct(ref data) => {
+                if let Some(tag) = &self.tag {
+                    errors.push(
+                        Error::custom("`tag` can only be used on enums").with_span(&tag.span()),
+                    );
+                }
+
                 if let Some(from_word) = &self.from_word {
                     if data.is_unit() {
                         errors.push(Error::custom("`from_word` cannot be used on unit structs because it conflicts with the generated impl").with_span(from_word));
@@ -160,6 +185,93 @@ impl ParseData for FromMetaOptions {
                     }
                 }
 
+                let other_variants = data
+                    .iter()
+                    .filter(|v| v.other.is_present())
+                    .collect::<Vec<_>>();
+
+                for variant in &other_variants {
+                    let other = variant.other.span();
+
+                    if other_variants.len() > 1 {
+                        errors.push(
+                            Error::custom("`#[darling(other)]` can only be applied to one variant")
+                                .with_span(&other),
+                        );
+                    }
+
+                    if variant.fields().len() != 1 || !variant.fields().is_tuple() {
+                        errors.push(
+                            Error::custom(
+                                "`#[darling(other)]` can only be applied to a variant with one unnamed field, such as `Other(String)`",
+                            )
+                            .with_span(&other),
+                        );
+                    }
+
+                    if variant.is_skipped() || variant.word.is_some() {
+                        errors.push(
+                            Error::custom("`other` cannot be used with `skip` or `word`")
+                                .with_span(&other),
+                        );
+                    }
+
+                    if self.tag.is_some() || self.untagged.is_present() {
+                        errors.push(
+                            Error::custom(
+                                "`other` cannot be used on enums with `tag` or `untagged`",
+                            )
+                            .with_span(&other),
+                        );
+                    }
+                }
+
+                // Untagged enums replace the whole `from_meta` method, so the options which
+                // customize individual formats would never be used.
+                if self.untagged.is_present() {
+                    let untagged = self.untagged.span();
+                    if self.tag.is_some() {
+                        errors.push(
+                            Error::custom("`tag` and `untagged` cannot be used together")
+                                .with_span(&untagged),
+                        );
+                    }
+
+                    if self.from_word.is_some() || data.iter().any(|v| v.word.is_some()) {
+                        errors.push(
+                            Error::custom("`untagged` cannot be used with `from_word` or `word`")
+                                .with_span(&untagged),
+                        );
+                    }
+
+                    if self.from_expr.is_some() {
+                        errors.push(
+                            Error::custom("`untagged` cannot be used with `from_expr`")
+                                .with_span(&untagged),
+                        );
+                    }
+                }
+
+                // The tag is removed before the variant reads its fields, so a field with the same
+                // name could never be set.
+                if let Some(tag) = &self.tag {
+                    for field in data.iter().flat_map(|v| v.fields().iter()) {
+                        let name = field
+                            .attr_name
+                            .clone()
+                            .unwrap_or_else(|| field.ident.to_string());
+                        if name == **tag || field.aliases.contains(tag) {
+                            errors.push(
+                                Error::custom(format!(
+                                    "Field `{}` conflicts with the enum's `tag`",
+                                    name
+                                ))
+                                .with_span(&field.ident),
+                            );
+                        }
+                    }
+                }
+
                 if let Some(from_expr) = &self.from_expr {
                     if data.iter().any(|v| v.is_unit_variant() && !v.is_skipped()) {
                         errors.push(
@@ -181,6 +293,8 @@ impl<'a> From<&'a FromMetaOptions> for FromMetaImpl<'a> {
             from_none: v.from_none.as_ref(),
             from_expr: v.from_expr.as_ref(),
             derive_syn_parse: v.derive_syn_parse.unwrap_or_default(),
+            tag: v.tag.as_ref().map(|tag| tag.as_str()),
+            untagged: v.untagged.is_present(),
         }
     }
 }
@@ -2,49 +2,137 @@ use std::borrow::Cow;
 
 use syn::{parse_quote_spanned, spanned::Spanned};
 
+use crate::ast::NestedMeta;
 use crate::codegen;
-use crate::options::{Core, DefaultExpression, ParseAttribute};
-use crate::util::{Callable, Flag, SpannedValue};
+use crate::options::{
+    min_exceeds_max, parse_optional_keys, Core, DefaultExpression, FieldValidators, OnDuplicate,
+    ParseAttribute, RenameAll,
+};
+use crate::util::{Callable, Flag, Override, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 #[derive(Debug, Clone)]
 pub struct InputField {
     pub ident: syn::Ident,
     pub attr_name: Option<String>,
+    /// The function which computes the field's name from `attr_name`, inherited from a
+    /// container with `rename_all = path::to::fn` when the field isn't explicitly renamed.
+    pub rename_with: Option<Callable>,
+    /// Additional names accepted for this field, e.g. from `#[darling(alias = "old_name")]`.
+    pub aliases: Vec<String>,
     pub ty: syn::Type,
     pub default: Option<DefaultExpression>,
+    /// The value from `default_value = ...`. It is turned into `default` before inheriting
+    /// settings from the container, unless it is a literal which the generated code must read
+    /// with the field type's `FromMeta::from_expr`.
+    pub default_value: Option<syn::Expr>,
+    /// The fallback computed from other fields' values, from `default_from(a, b, with = ...)`.
+    pub default_from: Option<DefaultFrom>,
     pub with: Option<Callable>,
+    /// Override for how the field is read when its name appears as a bare word.
+    pub from_word: Option<Callable>,
+    /// Override for how the field is read from the expression in `name = expr`.
+    pub from_expr: Option<Callable>,
+    /// Checks applied to each value read for the field, from `validate(range(min = 1), ...)`.
+    pub validators: Option<SpannedValue<FieldValidators>>,
 
     /// If `true`, generated code will not look for this field in the input meta item,
     /// instead always falling back to either `InputField::default` or `Default::default`.
     pub skip: Option<SpannedValue<bool>>,
     pub post_transform: Option<codegen::PostfixTransform>,
     pub multiple: Option<bool>,
+    /// Limits on the number of occurrences of a `multiple` field, from `multiple(min = 1, max = 3)`.
+    pub multiple_bounds: MultipleBounds,
+    /// The field of each item used as its key when a `multiple` field collects into a map,
+    /// from `#[darling(multiple, key = "name")]`.
+    pub key: Option<syn::Ident>,
     pub flatten: Flag,
+    /// Affixes which the keys of a flattened field must have, from `flatten(prefix = "http_")`.
+    pub flatten_affixes: FlattenAffixes,
+    /// If set, the field's key matches the input regardless of case, `_`, and `-`. When unset,
+    /// this is inherited from the container.
+    pub case_insensitive: Flag,
+    /// If set, the field can be populated by a leading literal or bare path in the input.
+    pub positional: Flag,
+    /// If set, the field collects every input item that no other field claimed.
+    pub rest: Flag,
+    /// If set, the field receives the warnings produced while parsing the container.
+    pub warnings: Flag,
+    /// How repeated occurrences of this field in the input are handled. When unset,
+    /// this is inherited from the container, and duplicates are an error if neither is set.
+    pub on_duplicate: Option<SpannedValue<OnDuplicate>>,
+    /// If set, a warning is reported whenever the field appears in the input.
+    /// The explicit value is a note appended to that warning.
+    pub deprecated: Option<Override<String>>,
+    /// Sibling fields that must not be present at the same time as this field.
+    pub conflicts_with: Vec<syn::Ident>,
+    /// Sibling fields that must be present whenever this field is present.
+    pub requires: Vec<syn::Ident>,
 }
 
 impl InputField {
     /// Generate a view into this field that can be used for code generation.
     pub fn as_codegen_field(&self) -> codegen::Field<'_> {
+        let name_in_attr = self
+            .attr_name
+            .as_deref()
+            .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed);
+
         codegen::Field {
             ident: &self.ident,
-            name_in_attr: self
-                .attr_name
-                .as_deref()
-                .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
+            deprecated: self.deprecated.as_ref(),
+            name_in_attr: codegen::AttrName::new(
+                name_in_attr,
+                self.rename_with.as_ref().map(AsRef::as_ref),
+            ),
+            aliases: &self.aliases,
             ty: &self.ty,
             default_expression: self.as_codegen_default(),
+            default_value: self.default_value.as_ref(),
+            default_from: self
+                .default_from
+                .as_ref()
+                .map(|d| (&d.fields[..], d.with.as_ref())),
             with_callable: self.with.as_ref().map(|w| w.as_ref()).map_or_else(
                 || Cow::Owned(parse_quote_spanned!(self.ty.span()=> _darling::FromMeta::from_meta)),
                 Cow::Borrowed,
             ),
+            custom_with: self.with.is_some(),
+            from_word: self.from_word.as_ref().map(AsRef::as_ref),
+            from_expr: self.from_expr.as_ref().map(AsRef::as_ref),
+            validators: self
+                .validators
+                .as_ref()
+                .map_or(&[], |validators| &validators.0[..]),
             skip: *self.skip.unwrap_or_default(),
             post_transform: self.post_transform.as_ref(),
             multiple: self.multiple.unwrap_or_default(),
+            min_items: self.multiple_bounds.min,
+            max_items: self.multiple_bounds.max,
+            key: self.key.as_ref(),
             flatten: self.flatten.is_present(),
+            flatten_prefix: self.flatten_affixes.prefix.as_deref().unwrap_or_default(),
+            flatten_suffix: self.flatten_affixes.suffix.as_deref().unwrap_or_default(),
+            case_insensitive: self.case_insensitive.is_present(),
+            positional: self.positional.is_present(),
+            rest: self.rest.is_present(),
+            warnings: self.warnings.is_present(),
+            on_duplicate: self.on_duplicate.map_or(OnDuplicate::Error, |v| *v),
+            conflicts_with: &self.conflicts_with,
+            requires: &self.requires,
+            track_span: false,
         }
     }
 
+    /// Whether this field can be set by name from the input, i.e. it is not skipped, flattened,
+    /// collecting the rest of the input, or receiving warnings.
+    pub fn is_addressable(&self) -> bool {
+        !self.flatten.is_present()
+            && !self.rest.is_present()
+            && !self.warnings.is_present()
+            && !self.skip.map(|v| *v).unwrap_or_default()
+    }
+
     /// Generate a codegen::DefaultExpression for this field. This requires the field name
     /// in the `Inherit` case.
     fn as_codegen_default(&self) -> Option<codegen::DefaultExpression<'_>> {
@@ -62,44 +150,282 @@ impl InputField {
             ident,
             ty,
             attr_name: None,
+            rename_with: None,
+            aliases: vec![],
             default: None,
+            default_value: None,
+            default_from: None,
             with: None,
+            from_word: None,
+            from_expr: None,
+            validators: None,
             skip: None,
             post_transform: Default::default(),
             multiple: None,
+            multiple_bounds: Default::default(),
+            key: None,
             flatten: Default::default(),
+            flatten_affixes: Default::default(),
+            case_insensitive: Default::default(),
+            positional: Default::default(),
+            rest: Default::default(),
+            warnings: Default::default(),
+            on_duplicate: None,
+            deprecated: None,
+            conflicts_with: vec![],
+            requires: vec![],
         }
     }
 
     pub fn from_field(f: &syn::Field, parent: Option<&Core>) -> Result<Self> {
+        let no_rule = RenameAll::default();
+        let rename_rule = parent.map_or(&no_rule, |container| &container.rename_rule);
+        Self::from_field_renamed(f, parent, rename_rule)
+    }
+
+    /// Parse a field of an enum variant, which is named by `rename_rule` rather than by the
+    /// rule the enum applies to its variants.
+    pub fn from_variant_field(
+        f: &syn::Field,
+        parent: Option<&Core>,
+        rename_rule: &RenameAll,
+    ) -> Result<Self> {
+        Self::from_field_renamed(f, parent, rename_rule)
+    }
+
+    fn from_field_renamed(
+        f: &syn::Field,
+        parent: Option<&Core>,
+        rename_rule: &RenameAll,
+    ) -> Result<Self> {
         let ident = f
             .ident
             .clone()
             .unwrap_or_else(|| syn::Ident::new("__unnamed", ::proc_macro2::Span::call_site()));
         let ty = f.ty.clone();
-        let base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
+        let mut base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
+        base.apply_default_value()?;
+        base.validate_unnamed()?;
+        base.validate_on_duplicate()?;
+        base.validate_key()?;
+        base.validate_conversions()?;
+        base.validate_default_from()?;
 
         Ok(if let Some(container) = parent {
-            base.with_inherited(container)
+            base.with_inherited(container, rename_rule)
         } else {
             base
         })
     }
 
+    /// Turn `default_value = ...` into the field's `default` if its value is known when deriving.
+    fn apply_default_value(&mut self) -> Result<()> {
+        let value = match &self.default_value {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+
+        if self.default.is_some() {
+            return Err(
+                Error::custom("`default` and `default_value` cannot be used together")
+                    .with_span(value),
+            );
+        }
+
+        if let Some(callable) = super::default_value::default_value_callable(&self.ty, value)? {
+            self.default = Some(DefaultExpression::Explicit(callable));
+            self.default_value = None;
+        }
+
+        Ok(())
+    }
+
+    /// Check that a `default_from` field has no other fallback and holds a single value.
+    fn validate_default_from(&self) -> Result<()> {
+        let default_from = match &self.default_from {
+            Some(default_from) => default_from,
+            None => return Ok(()),
+        };
+
+        let conflicts = [
+            ("default", self.default.is_some()),
+            ("default_value", self.default_value.is_some()),
+            ("multiple", self.multiple.unwrap_or_default()),
+            ("flatten", self.flatten.is_present()),
+            ("rest", self.rest.is_present()),
+            ("warnings", self.warnings.is_present()),
+        ];
+
+        let mut errors = Error::accumulator();
+        for (option, present) in conflicts {
+            if present {
+                errors.push(
+                    Error::custom(format!(
+                        "`default_from` and `{}` cannot be used together",
+                        option
+                    ))
+                    .with_span(&default_from.with),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that a `rest` or `warnings` field doesn't use options that only apply to fields
+    /// read by name.
+    ///
+    /// This runs before inheriting settings from the container, so that a container-level
+    /// `rename_all` isn't mistaken for an explicit `rename`.
+    fn validate_unnamed(&self) -> Result<()> {
+        let (kind, span) = if self.rest.is_present() {
+            ("rest", self.rest.span())
+        } else if self.warnings.is_present() {
+            ("warnings", self.warnings.span())
+        } else {
+            return Ok(());
+        };
+
+        let conflicts = [
+            ("rename", self.attr_name.is_some()),
+            ("alias", !self.aliases.is_empty()),
+            ("with", self.with.is_some()),
+            ("from_word", self.from_word.is_some()),
+            ("from_expr", self.from_expr.is_some()),
+            ("validate", self.validators.is_some()),
+            ("skip", self.skip.map(|v| *v).unwrap_or_default()),
+            ("multiple", self.multiple.unwrap_or_default()),
+            ("flatten", self.flatten.is_present()),
+            ("positional", self.positional.is_present()),
+            ("deprecated", self.deprecated.is_some()),
+            ("on_duplicate", self.on_duplicate.is_some()),
+            ("conflicts_with", !self.conflicts_with.is_empty()),
+            ("requires", !self.requires.is_empty()),
+            ("warnings", kind == "rest" && self.warnings.is_present()),
+            // The warnings are always known, so there is nothing to fall back on.
+            ("default", kind == "warnings" && self.default.is_some()),
+            (
+                "default_value",
+                kind == "warnings" && self.default_value.is_some(),
+            ),
+        ];
+
+        let mut errors = Error::accumulator();
+        for (option, present) in conflicts {
+            if present {
+                errors.push(
+                    Error::custom(format!(
+                        "`{}` and `{}` cannot be used together",
+                        kind, option
+                    ))
+                    .with_span(&span),
+                );
+            }
+        }
+
+        if let Some(post_transform) = &self.post_transform {
+            errors.push(
+                Error::custom(format!(
+                    "`{}` and `{}` cannot be used together",
+                    kind, post_transform.transformer
+                ))
+                .with_span(&span),
+            );
+        }
+
+        errors.finish()
+    }
+
+    /// Check that an explicit `on_duplicate` is only used on fields which take a single value by name.
+    fn validate_on_duplicate(&self) -> Result<()> {
+        let span = match &self.on_duplicate {
+            Some(on_duplicate) => on_duplicate.span(),
+            None => return Ok(()),
+        };
+
+        let conflicts = [
+            ("multiple", self.multiple.unwrap_or_default()),
+            ("flatten", self.flatten.is_present()),
+            ("skip", self.skip.map(|v| *v).unwrap_or_default()),
+        ];
+
+        let mut errors = Error::accumulator();
+        for (option, present) in conflicts {
+            if present {
+                errors.push(
+                    Error::custom(format!(
+                        "`on_duplicate` and `{}` cannot be used together",
+                        option
+                    ))
+                    .with_span(&span),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that `key` is only used on fields which collect multiple values.
+    fn validate_key(&self) -> Result<()> {
+        match &self.key {
+            Some(key) if !self.multiple.unwrap_or_default() => {
+                Err(Error::custom("`key` can only be used with `multiple`").with_span(key))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that `from_word`, `from_expr`, and `validate` are only used on fields which read a
+    /// value by name.
+    fn validate_conversions(&self) -> Result<()> {
+        let conflicts = [
+            ("flatten", self.flatten.is_present()),
+            ("skip", self.skip.map(|v| *v).unwrap_or_default()),
+        ];
+
+        let mut errors = Error::accumulator();
+        for (name, span) in [
+            ("from_word", self.from_word.as_ref().map(Spanned::span)),
+            ("from_expr", self.from_expr.as_ref().map(Spanned::span)),
+            ("validate", self.validators.as_ref().map(SpannedValue::span)),
+        ] {
+            let span = match span {
+                Some(span) => span,
+                None => continue,
+            };
+
+            for (option, present) in conflicts {
+                if present {
+                    errors.push(
+                        Error::custom(format!(
+                            "`{}` and `{}` cannot be used together",
+                            name, option
+                        ))
+                        .with_span(&span),
+                    );
+                }
+            }
+        }
+
+        errors.finish()
+    }
+
     /// Apply inherited settings from the container. This is done _after_ parsing
     /// to ensure deference to explicit field-level settings.
-    fn with_inherited(mut self, parent: &Core) -> Self {
+    fn with_inherited(mut self, parent: &Core, rename_rule: &RenameAll) -> Self {
         // explicit renamings take precedence over rename rules on the container,
         // but in the absence of an explicit name we apply the rule.
         if self.attr_name.is_none() {
-            self.attr_name = Some(parent.rename_rule.apply_to_field(self.ident.to_string()));
+            self.attr_name = Some(rename_rule.apply_to_field(&self.ident.to_string()));
+            self.rename_with = rename_rule.with().cloned();
         }
 
         // Determine the default expression for this field, based on three pieces of information:
         // 1. Will we look for this field in the attribute?
         // 1. Is there a locally-defined default?
         // 1. Did the parent define a default?
-        self.default = match (&self.skip, self.default.is_some(), parent.default.is_some()) {
+        let has_default = self.default.is_some() || self.default_value.is_some();
+        self.default = match (&self.skip, has_default, parent.default.is_some()) {
             // If we have a default, use it.
             (_, true, _) => self.default,
 
@@ -117,6 +443,14 @@ impl InputField {
             (_, false, false) => None,
         };
 
+        if self.on_duplicate.is_none() {
+            self.on_duplicate = parent.on_duplicate;
+        }
+
+        if !self.case_insensitive.is_present() {
+            self.case_insensitive = parent.case_insensitive;
+        }
+
         self
     }
 }
@@ -137,11 +471,34 @@ impl ParseAttribute for InputField {
                     Error::custom("`flatten` and `rename` cannot be used together").with_span(mi),
                 );
             }
+        } else if path.is_ident("alias") {
+            self.aliases.push(FromMeta::from_meta(mi)?);
+
+            if self.flatten.is_present() {
+                return Err(
+                    Error::custom("`flatten` and `alias` cannot be used together").with_span(mi),
+                );
+            }
         } else if path.is_ident("default") {
             if self.default.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
             self.default = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("default_value") {
+            if self.default_value.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.default_value = match mi {
+                syn::Meta::NameValue(nv) => Some(nv.value.clone()),
+                _ => return Err(Error::unsupported_format("non-value").with_span(mi)),
+            };
+        } else if path.is_ident("default_from") {
+            if self.default_from.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.default_from = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("with") {
             if self.with.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
@@ -154,6 +511,24 @@ impl ParseAttribute for InputField {
                     Error::custom("`flatten` and `with` cannot be used together").with_span(mi),
                 );
             }
+        } else if path.is_ident("from_word") {
+            if self.from_word.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.from_word = Some(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("from_expr") {
+            if self.from_expr.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.from_expr = Some(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("validate") {
+            if self.validators.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.validators = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("skip") {
             if self.skip.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
@@ -189,19 +564,87 @@ impl ParseAttribute for InputField {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
-            self.multiple = FromMeta::from_meta(mi)?;
+            if let syn::Meta::List(_) = mi {
+                self.multiple_bounds = FromMeta::from_meta(mi).map_err(|e| e.with_span(mi))?;
+                self.multiple = Some(true);
+            } else {
+                self.multiple = FromMeta::from_meta(mi)?;
+            }
 
             if self.multiple.unwrap_or(false) && self.flatten.is_present() {
                 return Err(
                     Error::custom("`flatten` and `multiple` cannot be used together").with_span(mi),
                 );
             }
+        } else if path.is_ident("key") {
+            if self.key.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            // Give the identifier the span of the input, so that a key which isn't a field
+            // of the item type is reported there.
+            let key = SpannedValue::<syn::Ident>::from_meta(mi)?;
+            let mut ident = key.as_ref().clone();
+            ident.set_span(key.span());
+            self.key = Some(ident);
+        } else if path.is_ident("deprecated") {
+            if self.deprecated.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.deprecated = FromMeta::from_meta(mi)?;
+
+            if self.flatten.is_present() {
+                return Err(
+                    Error::custom("`flatten` and `deprecated` cannot be used together")
+                        .with_span(mi),
+                );
+            }
+        } else if path.is_ident("case_insensitive") {
+            if self.case_insensitive.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.case_insensitive = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("positional") {
+            if self.positional.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.positional = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("on_duplicate") {
+            if self.on_duplicate.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.on_duplicate = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("rest") {
+            if self.rest.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.rest = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("warnings") {
+            if self.warnings.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.warnings = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("conflicts_with") {
+            self.conflicts_with.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("requires") {
+            self.requires.push(FromMeta::from_meta(mi)?);
         } else if path.is_ident("flatten") {
             if self.flatten.is_present() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
-            self.flatten = FromMeta::from_meta(mi)?;
+            if let syn::Meta::List(_) = mi {
+                self.flatten_affixes = FromMeta::from_meta(mi).map_err(|e| e.with_span(mi))?;
+                self.flatten = FromMeta::from_meta(&syn::Meta::Path(path.clone()))?;
+            } else {
+                self.flatten = FromMeta::from_meta(mi)?;
+            }
 
             let mut conflicts = Error::accumulator();
 
@@ -217,6 +660,19 @@ impl ParseAttribute for InputField {
                 );
             }
 
+            if !self.aliases.is_empty() {
+                conflicts.push(
+                    Error::custom("`flatten` and `alias` cannot be used together").with_span(mi),
+                );
+            }
+
+            if self.deprecated.is_some() {
+                conflicts.push(
+                    Error::custom("`flatten` and `deprecated` cannot be used together")
+                        .with_span(mi),
+                );
+            }
+
             if self.with.is_some() {
                 conflicts.push(
                     Error::custom("`flatten` and `with` cannot be used together").with_span(mi),
@@ -237,3 +693,113 @@ impl ParseAttribute for InputField {
         Ok(())
     }
 }
+
+/// The occurrence limits in `#[darling(multiple(min = 1, max = 3))]`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MultipleBounds {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+}
+
+impl FromMeta for MultipleBounds {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut bounds = MultipleBounds::default();
+        let mut errors = Error::accumulator();
+        parse_optional_keys(
+            items,
+            &mut [("min", &mut bounds.min), ("max", &mut bounds.max)],
+            &mut errors,
+        );
+
+        if let (Some(min), Some(max)) = (bounds.min, bounds.max) {
+            if min > max {
+                errors.push(min_exceeds_max());
+            }
+        }
+
+        errors.finish_with(bounds)
+    }
+}
+
+/// A fallback computed from sibling fields, from
+/// `#[darling(default_from(tls, with = |tls: &bool| if *tls { 443 } else { 80 }))]`.
+///
+/// `with` is called with a reference to the final value of each listed field, in order.
+#[derive(Debug, Clone)]
+pub struct DefaultFrom {
+    pub fields: Vec<syn::Ident>,
+    pub with: Callable,
+}
+
+impl FromMeta for DefaultFrom {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut fields = vec![];
+        let mut with = None;
+        let mut errors = Error::accumulator();
+
+        for item in items {
+            let mi = match item {
+                NestedMeta::Meta(mi) => mi,
+                NestedMeta::Lit(lit) => {
+                    errors.push(Error::unsupported_format("literal").with_span(lit));
+                    continue;
+                }
+            };
+
+            match mi {
+                syn::Meta::Path(path) if path.get_ident().is_some() => {
+                    fields.push(path.get_ident().unwrap().clone());
+                }
+                _ if mi.path().is_ident("with") => {
+                    if with.is_some() {
+                        errors.push(Error::duplicate_field("with").with_span(mi));
+                        continue;
+                    }
+
+                    with = errors.handle(FromMeta::from_meta(mi).map_err(|e: Error| e.at("with")));
+                }
+                _ => errors
+                    .push(Error::unknown_field_path_with_alts(mi.path(), &["with"]).with_span(mi)),
+            }
+        }
+
+        if fields.is_empty() {
+            errors.push(Error::custom("`default_from` must name at least one field"));
+        }
+
+        if with.is_none() {
+            errors.push(Error::missing_field("with"));
+        }
+
+        errors.finish()?;
+
+        Ok(DefaultFrom {
+            fields,
+            with: with.expect("Missing `with` was already reported"),
+        })
+    }
+}
+
+/// The key affixes in `#[darling(flatten(prefix = "http_", suffix = "_ms"))]`.
+#[derive(Debug, Default, Clone)]
+pub struct FlattenAffixes {
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+}
+
+impl FromMeta for FlattenAffixes {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut affixes = FlattenAffixes::default();
+        let mut errors = Error::accumulator();
+        parse_optional_keys(
+            items,
+            &mut [
+                ("prefix", &mut affixes.prefix),
+                ("suffix", &mut affixes.suffix),
+            ],
+            &mut errors,
+        );
+
+        errors.finish_with(affixes)
+    }
+}
@@ -2,14 +2,18 @@ use std::borrow::Cow;
 
 use crate::ast::Fields;
 use crate::codegen;
-use crate::options::{Core, InputField, ParseAttribute};
-use crate::util::{Flag, SpannedValue};
+use crate::options::{Core, InputField, ParseAttribute, RenameAll};
+use crate::util::{Callable, Flag, Override, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 #[derive(Debug, Clone)]
 pub struct InputVariant {
     pub ident: syn::Ident,
     attr_name: Option<String>,
+    /// The function which computes the variant's name from `attr_name`, inherited from an enum
+    /// with `rename_all = path::to::fn` when the variant isn't explicitly renamed.
+    rename_with: Option<Callable>,
+    aliases: Vec<String>,
     data: Fields<InputField>,
     skip: Option<bool>,
     /// Whether or not the variant should be used to create an instance for
@@ -18,6 +22,14 @@ pub struct InputVariant {
     /// Whether or not unknown fields are acceptable in this
     allow_unknown_fields: Option<bool>,
     pub transparent: Flag,
+    /// If set, a warning is reported whenever the variant is selected by the input.
+    deprecated: Option<Override<String>>,
+    /// Whether this variant receives values that don't match any other variant.
+    pub other: Flag,
+    /// Whether the variant's name matches the input regardless of case, `_`, and `-`.
+    case_insensitive: Flag,
+    /// The rule used to name the variant's fields, overriding the enum's rules.
+    rename_all: Option<RenameAll>,
 }
 
 impl InputVariant {
@@ -29,18 +41,52 @@ impl InputVariant {
         self.skip.unwrap_or_default()
     }
 
+    pub fn fields(&self) -> &Fields<InputField> {
+        &self.data
+    }
+
+    /// The names which select this variant in the input and are known when deriving, and whether
+    /// they match regardless of case. This is empty for skipped and catch-all variants, and leaves
+    /// out a name computed by a `rename_all` function.
+    pub fn input_names(&self) -> (Vec<&str>, bool) {
+        if self.is_skipped() || self.other.is_present() {
+            return (vec![], false);
+        }
+
+        let names = self
+            .attr_name
+            .iter()
+            .filter(|_| self.rename_with.is_none())
+            .chain(&self.aliases)
+            .map(String::as_str)
+            .collect();
+        (names, self.case_insensitive.is_present())
+    }
+
     pub fn as_codegen_variant<'a>(&'a self, ty_ident: &'a syn::Ident) -> codegen::Variant<'a> {
+        let name_in_attr = self
+            .attr_name
+            .as_deref()
+            .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed);
+
+        let mut data = self.data.as_ref().map(InputField::as_codegen_field);
+        codegen::track_constrained_spans(&mut data.fields, &[]);
+
         codegen::Variant {
             ty_ident,
             variant_ident: &self.ident,
-            name_in_attr: self
-                .attr_name
-                .as_deref()
-                .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
-            data: self.data.as_ref().map(InputField::as_codegen_field),
+            deprecated: self.deprecated.as_ref(),
+            name_in_attr: codegen::AttrName::new(
+                name_in_attr,
+                self.rename_with.as_ref().map(AsRef::as_ref),
+            ),
+            aliases: &self.aliases,
+            data,
             skip: self.is_skipped(),
             allow_unknown_fields: self.allow_unknown_fields.unwrap_or_default(),
             transparent: self.transparent.is_present(),
+            other: self.other.is_present(),
+            case_insensitive: self.case_insensitive.is_present(),
         }
     }
 
@@ -48,20 +94,32 @@ impl InputVariant {
         let mut starter = (InputVariant {
             ident: v.ident.clone(),
             attr_name: Default::default(),
+            rename_with: None,
+            aliases: Default::default(),
             data: Fields::empty_from(&v.fields),
             skip: Default::default(),
             word: Default::default(),
             allow_unknown_fields: None,
             transparent: Flag::default(),
+            deprecated: None,
+            other: Flag::default(),
+            case_insensitive: Flag::default(),
+            rename_all: None,
         })
         .parse_attributes(&v.attrs)?;
 
+        let rename_rule = starter
+            .rename_all
+            .clone()
+            .or_else(|| parent.map(|p| p.variant_field_rename_rule().clone()))
+            .unwrap_or_default();
+
         starter.data.fields = match v.fields {
             syn::Fields::Unit => vec![],
             syn::Fields::Unnamed(ref fields) => {
                 let mut items = Vec::with_capacity(fields.unnamed.len());
                 for item in &fields.unnamed {
-                    items.push(InputField::from_field(item, parent)?);
+                    items.push(InputField::from_variant_field(item, parent, &rename_rule)?);
                 }
 
                 items
@@ -69,7 +127,7 @@ impl InputVariant {
             syn::Fields::Named(ref fields) => {
                 let mut items = Vec::with_capacity(fields.named.len());
                 for item in &fields.named {
-                    items.push(InputField::from_field(item, parent)?);
+                    items.push(InputField::from_variant_field(item, parent, &rename_rule)?);
                 }
 
                 items
@@ -85,13 +143,18 @@ impl InputVariant {
 
     fn with_inherited(mut self, parent: &Core) -> Self {
         if self.attr_name.is_none() {
-            self.attr_name = Some(parent.rename_rule.apply_to_variant(self.ident.to_string()));
+            self.attr_name = Some(parent.rename_rule.apply_to_variant(&self.ident.to_string()));
+            self.rename_with = parent.rename_rule.with().cloned();
         }
 
         if self.allow_unknown_fields.is_none() {
             self.allow_unknown_fields = Some(parent.allow_unknown_fields.unwrap_or_default());
         }
 
+        if !self.case_insensitive.is_present() {
+            self.case_insensitive = parent.case_insensitive;
+        }
+
         self
     }
 }
@@ -105,18 +168,44 @@ impl ParseAttribute for InputVariant {
             }
 
             self.attr_name = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("alias") {
+            self.aliases.push(FromMeta::from_meta(mi)?);
         } else if path.is_ident("skip") {
             if self.skip.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
             self.skip = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("deprecated") {
+            if self.deprecated.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.deprecated = FromMeta::from_meta(mi)?;
         } else if path.is_ident("transparent") {
             if self.transparent.is_present() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
             }
 
             self.transparent = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("rename_all") {
+            if self.rename_all.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.rename_all = Some(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("case_insensitive") {
+            if self.case_insensitive.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.case_insensitive = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("other") {
+            if self.other.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.other = FromMeta::from_meta(mi)?;
         } else if path.is_ident("word") {
             if self.word.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
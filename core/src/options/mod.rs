@@ -7,6 +7,7 @@ use crate::util::Callable;
 use crate::{Error, FromMeta, Result};
 
 mod core;
+mod default_value;
 mod forward_attrs;
 mod forwarded_field;
 mod from_attributes;
@@ -18,7 +19,9 @@ mod from_variant;
 mod input_field;
 mod input_variant;
 mod outer_from;
+mod rename_all;
 mod shape;
+mod validators;
 
 pub use self::core::Core;
 pub use self::forward_attrs::ForwardAttrsFilter;
@@ -32,7 +35,9 @@ pub use self::from_variant::FromVariantOptions;
 pub use self::input_field::InputField;
 pub use self::input_variant::InputVariant;
 pub use self::outer_from::OuterFrom;
+pub use self::rename_all::RenameAll;
 pub use self::shape::{DataShape, DeriveInputShapeSet};
+pub use self::validators::{FieldValidator, FieldValidators};
 
 /// A default/fallback expression encountered in attributes during parsing.
 #[derive(Debug, Clone)]
@@ -69,6 +74,35 @@ impl FromMeta for DefaultExpression {
     }
 }
 
+/// How a field handles its key appearing more than once in the input, from
+/// `#[darling(on_duplicate = "...")]` on a field or container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnDuplicate {
+    /// Report a duplicate field error.
+    Error,
+    /// Keep the first value and ignore later ones.
+    First,
+    /// Keep the last value.
+    Last,
+    /// Combine the items of every occurrence into a single list before parsing it.
+    Merge,
+}
+
+impl FromMeta for OnDuplicate {
+    fn from_string(value: &str) -> Result<Self> {
+        match value {
+            "error" => Ok(OnDuplicate::Error),
+            "first" => Ok(OnDuplicate::First),
+            "last" => Ok(OnDuplicate::Last),
+            "merge" => Ok(OnDuplicate::Merge),
+            other => Err(Error::unknown_value_with_alts(
+                other,
+                &["error", "first", "last", "merge"],
+            )),
+        }
+    }
+}
+
 /// Middleware for extracting attribute values. Implementers are expected to override
 /// `parse_nested` so they can apply individual items to themselves, while `parse_attributes`
 /// is responsible for looping through distinct outer attributes and collecting errors.
@@ -106,6 +140,49 @@ fn parse_attr<T: ParseAttribute>(attr: &syn::Attribute, target: &mut T) -> Resul
     }
 }
 
+/// Read a list of `key = value` items into `slots`, each of which is named by its key.
+///
+/// Literals, keys that aren't in `slots`, and keys that appear twice are reported to `errors`,
+/// as is a value that can't be read, located at its key. Used by options such as
+/// `multiple(min = 1, max = 3)` whose keys are all optional.
+fn parse_optional_keys<T: FromMeta>(
+    items: &[NestedMeta],
+    slots: &mut [(&str, &mut Option<T>)],
+    errors: &mut Accumulator,
+) {
+    for item in items {
+        let mi = match item {
+            NestedMeta::Meta(mi) => mi,
+            NestedMeta::Lit(lit) => {
+                errors.push(Error::unsupported_format("literal").with_span(lit));
+                continue;
+            }
+        };
+
+        let path = mi.path();
+        let slot = match slots.iter_mut().find(|(key, _)| path.is_ident(key)) {
+            Some((_, slot)) => slot,
+            None => {
+                let keys = slots.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+                errors.push(Error::unknown_field_path_with_alts(path, &keys).with_span(mi));
+                continue;
+            }
+        };
+
+        if slot.is_some() {
+            errors.push(Error::duplicate_field_path(path).with_span(mi));
+            continue;
+        }
+
+        **slot = errors.handle(FromMeta::from_meta(mi).map_err(|e: Error| e.at_path(path)));
+    }
+}
+
+/// The error for a `min` bound which is greater than the `max` bound it's paired with.
+fn min_exceeds_max() -> Error {
+    Error::custom("`min` must not be greater than `max`")
+}
+
 /// Middleware for extracting values from the body of the derive input. Implementers are
 /// expected to override `parse_field` or `parse_variant` as appropriate for their use-case,
 /// while `parse_body` dispatches to the appropriate methods and handles error collection.
@@ -0,0 +1,244 @@
+use ident_case::RenameRule;
+
+use crate::ast::NestedMeta;
+use crate::util::Callable;
+use crate::{Error, FromMeta, Result};
+
+/// How the fields or variants of a container are named in the input, from `rename_all`.
+///
+/// This is a case rule such as `"camelCase"`, a path to a function which maps `&str` to
+/// `String`, or a list which can also remove a prefix or suffix from each name first:
+/// `rename_all(case = "kebab-case", strip_prefix = "opt_")`.
+#[derive(Debug, Clone, Default)]
+pub struct RenameAll {
+    case: Option<Case>,
+    with: Option<Callable>,
+    strip_prefix: Option<String>,
+    strip_suffix: Option<String>,
+}
+
+impl RenameAll {
+    /// Use the case rule of `fallback` if this doesn't set a case rule or a function, so that
+    /// `rename_all(strip_prefix = "...")` keeps the default rule for the container's shape.
+    pub fn or_case_of(mut self, fallback: &RenameAll) -> Self {
+        if self.case.is_none() && self.with.is_none() {
+            self.case = fallback.case;
+        }
+
+        self
+    }
+
+    /// The function which computes each name from the result of `apply_to_field` or
+    /// `apply_to_variant`. It can only be called by the generated code.
+    pub fn with(&self) -> Option<&Callable> {
+        self.with.as_ref()
+    }
+
+    pub fn apply_to_field(&self, name: &str) -> String {
+        let name = self.strip(name);
+        match self.case {
+            Some(Case::Ident(rule)) => rule.apply_to_field(name),
+            Some(Case::ScreamingKebab) => RenameRule::KebabCase
+                .apply_to_field(name)
+                .to_ascii_uppercase(),
+            None => name.to_string(),
+        }
+    }
+
+    pub fn apply_to_variant(&self, name: &str) -> String {
+        let name = self.strip(name);
+        match self.case {
+            Some(Case::Ident(rule)) => rule.apply_to_variant(name),
+            Some(Case::ScreamingKebab) => RenameRule::KebabCase
+                .apply_to_variant(name)
+                .to_ascii_uppercase(),
+            None => name.to_string(),
+        }
+    }
+
+    /// Remove the prefix and suffix from `name`, unless that would leave nothing.
+    fn strip<'a>(&self, name: &'a str) -> &'a str {
+        let stripped = self
+            .strip_prefix
+            .as_deref()
+            .and_then(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(name);
+        let stripped = self
+            .strip_suffix
+            .as_deref()
+            .and_then(|suffix| stripped.strip_suffix(suffix))
+            .unwrap_or(stripped);
+
+        if stripped.is_empty() {
+            name
+        } else {
+            stripped
+        }
+    }
+}
+
+impl From<RenameRule> for RenameAll {
+    fn from(rule: RenameRule) -> Self {
+        RenameAll {
+            case: Some(Case::Ident(rule)),
+            ..Default::default()
+        }
+    }
+}
+
+impl FromMeta for RenameAll {
+    fn from_string(value: &str) -> Result<Self> {
+        Ok(RenameAll {
+            case: Some(Case::from_string(value)?),
+            ..Default::default()
+        })
+    }
+
+    fn from_expr(expr: &syn::Expr) -> Result<Self> {
+        match expr {
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit).map_err(|e| e.with_span(lit)),
+            // See `FromMeta::from_expr`.
+            syn::Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Ok(RenameAll {
+                with: Some(Callable::from_expr(expr)?),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut rename = RenameAll::default();
+        let mut errors = Error::accumulator();
+
+        for item in items {
+            let mi = match item {
+                NestedMeta::Meta(mi) => mi,
+                NestedMeta::Lit(lit) => {
+                    errors.push(Error::unsupported_format("literal").with_span(lit));
+                    continue;
+                }
+            };
+
+            let path = mi.path();
+            let duplicate = if path.is_ident("case") {
+                let duplicate = rename.case.is_some();
+                rename.case = errors.handle(FromMeta::from_meta(mi));
+                duplicate
+            } else if path.is_ident("with") {
+                let duplicate = rename.with.is_some();
+                rename.with = errors.handle(FromMeta::from_meta(mi));
+                duplicate
+            } else if path.is_ident("strip_prefix") {
+                let duplicate = rename.strip_prefix.is_some();
+                rename.strip_prefix = errors.handle(FromMeta::from_meta(mi));
+                duplicate
+            } else if path.is_ident("strip_suffix") {
+                let duplicate = rename.strip_suffix.is_some();
+                rename.strip_suffix = errors.handle(FromMeta::from_meta(mi));
+                duplicate
+            } else {
+                errors.push(
+                    Error::unknown_field_path_with_alts(
+                        path,
+                        &["case", "with", "strip_prefix", "strip_suffix"],
+                    )
+                    .with_span(mi),
+                );
+                continue;
+            };
+
+            if duplicate {
+                errors.push(Error::duplicate_field_path(path).with_span(mi));
+            }
+        }
+
+        if rename.case.is_some() && rename.with.is_some() {
+            errors.push(Error::custom("`case` and `with` cannot both be set"));
+        }
+
+        errors.finish_with(rename)
+    }
+}
+
+/// A case rule: one of `ident_case`'s rules, or `SCREAMING-KEBAB-CASE`.
+#[derive(Debug, Clone, Copy)]
+enum Case {
+    Ident(RenameRule),
+    ScreamingKebab,
+}
+
+impl FromMeta for Case {
+    fn from_string(value: &str) -> Result<Self> {
+        if value == "SCREAMING-KEBAB-CASE" {
+            Ok(Case::ScreamingKebab)
+        } else {
+            RenameRule::from_string(value).map(Case::Ident)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::RenameAll;
+    use crate::FromMeta;
+
+    fn parse(meta: syn::Meta) -> RenameAll {
+        RenameAll::from_meta(&meta).unwrap()
+    }
+
+    #[test]
+    fn case_rules() {
+        let rename = parse(parse_quote!(rename_all = "SCREAMING-KEBAB-CASE"));
+        assert_eq!(rename.apply_to_field("max_size"), "MAX-SIZE");
+        assert_eq!(rename.apply_to_variant("ReadOnly"), "READ-ONLY");
+
+        let rename = parse(parse_quote!(rename_all = "camelCase"));
+        assert_eq!(rename.apply_to_field("max_size"), "maxSize");
+        assert!(rename.with().is_none());
+    }
+
+    #[test]
+    fn strip_affixes() {
+        let rename = parse(parse_quote!(rename_all(
+            case = "kebab-case",
+            strip_prefix = "opt_",
+            strip_suffix = "_ms"
+        )));
+        assert_eq!(rename.apply_to_field("opt_wait_time_ms"), "wait-time");
+        assert_eq!(rename.apply_to_field("verbose"), "verbose");
+        assert_eq!(rename.apply_to_field("opt_"), "opt-");
+
+        let rename = parse(parse_quote!(rename_all(strip_prefix = "Opt")));
+        assert_eq!(rename.apply_to_variant("OptLevel"), "Level");
+    }
+
+    #[test]
+    fn function() {
+        let rename = parse(parse_quote!(rename_all = str::to_uppercase));
+        assert!(rename.with().is_some());
+        assert_eq!(rename.apply_to_field("max_size"), "max_size");
+
+        let rename = parse(parse_quote!(rename_all(
+            with = str::to_uppercase,
+            strip_prefix = "opt_"
+        )));
+        assert_eq!(rename.apply_to_field("opt_level"), "level");
+    }
+
+    #[test]
+    fn invalid() {
+        let message = |meta: syn::Meta| RenameAll::from_meta(&meta).unwrap_err().to_string();
+
+        assert_eq!(
+            message(parse_quote!(rename_all(
+                case = "camelCase",
+                with = str::to_uppercase
+            ))),
+            "`case` and `with` cannot both be set"
+        );
+        assert!(message(parse_quote!(rename_all = "Title Case"))
+            .starts_with("Unknown value: `Title Case`"));
+    }
+}
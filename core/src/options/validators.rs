@@ -0,0 +1,242 @@
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Expr, ExprLit, Meta};
+
+use crate::ast::NestedMeta;
+use crate::options::input_field::MultipleBounds;
+use crate::options::{min_exceeds_max, parse_optional_keys};
+use crate::{Error, FromMeta, Result};
+
+/// The checks applied to each value read for a field, from
+/// `#[darling(validate(range(min = 1), non_empty, len(max = 64), one_of("a", "b")))]`.
+#[derive(Debug, Clone, Default)]
+pub struct FieldValidators(pub Vec<FieldValidator>);
+
+/// A single check from a field's `validate(...)` list.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum FieldValidator {
+    /// The value must lie within the bounds, from `range(min = 1, max = 65535)`.
+    Range {
+        min: Option<Expr>,
+        max: Option<Expr>,
+        span: Span,
+    },
+    /// The value must have at least one character or item, from `non_empty`.
+    NonEmpty { span: Span },
+    /// The value's number of characters or items must lie within the bounds, from `len(max = 64)`.
+    Len { bounds: MultipleBounds, span: Span },
+    /// The value must equal one of the listed literals, from `one_of("a", "b")`.
+    OneOf { values: Vec<Expr>, span: Span },
+}
+
+impl FieldValidator {
+    const NAMES: &'static [&'static str] = &["range", "non_empty", "len", "one_of"];
+
+    fn from_nested_meta(mi: &Meta) -> Result<Self> {
+        let path = mi.path();
+        let span = mi.span();
+
+        if path.is_ident("range") {
+            let RangeBounds { min, max } = FromMeta::from_meta(mi)?;
+            if min.is_none() && max.is_none() {
+                return Err(Error::custom("`range` requires `min`, `max`, or both").with_span(mi));
+            }
+            Ok(FieldValidator::Range { min, max, span })
+        } else if path.is_ident("non_empty") {
+            match mi {
+                Meta::Path(_) => Ok(FieldValidator::NonEmpty { span }),
+                _ => Err(Error::unsupported_format("non-word").with_span(mi)),
+            }
+        } else if path.is_ident("len") {
+            let bounds: MultipleBounds = FromMeta::from_meta(mi)?;
+            if bounds.min.is_none() && bounds.max.is_none() {
+                return Err(Error::custom("`len` requires `min`, `max`, or both").with_span(mi));
+            }
+            Ok(FieldValidator::Len { bounds, span })
+        } else if path.is_ident("one_of") {
+            let items = match mi {
+                Meta::List(list) => NestedMeta::parse_meta_list(list.tokens.clone())?,
+                _ => return Err(Error::unsupported_format("non-list").with_span(mi)),
+            };
+
+            let values = items
+                .into_iter()
+                .map(|item| match item {
+                    NestedMeta::Lit(lit) => Ok(Expr::Lit(ExprLit { attrs: vec![], lit })),
+                    NestedMeta::Meta(meta) => {
+                        Err(Error::custom("Expected a literal").with_span(&meta))
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            if values.is_empty() {
+                return Err(Error::custom("`one_of` requires at least one value").with_span(mi));
+            }
+
+            Ok(FieldValidator::OneOf { values, span })
+        } else {
+            Err(Error::unknown_field_path_with_alts(path, Self::NAMES).with_span(mi))
+        }
+    }
+}
+
+impl FromMeta for FieldValidators {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        if items.is_empty() {
+            return Err(Error::custom("`validate` requires at least one check"));
+        }
+
+        let mut validators = vec![];
+        let mut errors = Error::accumulator();
+
+        for item in items {
+            match item {
+                NestedMeta::Meta(mi) => {
+                    if let Some(validator) = errors.handle(FieldValidator::from_nested_meta(mi)) {
+                        validators.push(validator);
+                    }
+                }
+                NestedMeta::Lit(lit) => {
+                    errors.push(Error::unsupported_format("literal").with_span(lit));
+                }
+            }
+        }
+
+        errors.finish_with(FieldValidators(validators))
+    }
+}
+
+/// The bounds of a `range(...)` check, which are expressions of the field's value type.
+#[derive(Debug, Default)]
+struct RangeBounds {
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+impl FromMeta for RangeBounds {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut bounds = RangeBounds::default();
+        let mut errors = Error::accumulator();
+        parse_optional_keys(
+            items,
+            &mut [("min", &mut bounds.min), ("max", &mut bounds.max)],
+            &mut errors,
+        );
+
+        if let (Some(min), Some(max)) = (&bounds.min, &bounds.max) {
+            if let (Some(min), Some(max)) = (Number::of(min), Number::of(max)) {
+                if min.exceeds(max) {
+                    errors.push(min_exceeds_max());
+                }
+            }
+        }
+
+        errors.finish_with(bounds)
+    }
+}
+
+/// A numeric literal bound, so that bounds which are known when deriving can be compared.
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+impl Number {
+    /// Get the value of `expr` if it is an integer or float literal, optionally negated.
+    fn of(expr: &Expr) -> Option<Self> {
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => match lit {
+                syn::Lit::Int(int) => int.base10_parse().ok().map(Number::Int),
+                syn::Lit::Float(float) => float.base10_parse().ok().map(Number::Float),
+                _ => None,
+            },
+            Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => match Number::of(expr)? {
+                Number::Int(value) => Some(Number::Int(-value)),
+                Number::Float(value) => Some(Number::Float(-value)),
+            },
+            Expr::Group(group) => Number::of(&group.expr),
+            Expr::Paren(paren) => Number::of(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Whether `self` is greater than `other`. Numbers of different kinds aren't compared, since
+    /// the compiler rejects bounds of mismatched types anyway.
+    fn exceeds(self, other: Number) -> bool {
+        match (self, other) {
+            (Number::Int(a), Number::Int(b)) => a > b,
+            (Number::Float(a), Number::Float(b)) => a > b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{FieldValidator, FieldValidators};
+    use crate::FromMeta;
+
+    fn parse(meta: syn::Meta) -> crate::Result<Vec<FieldValidator>> {
+        FieldValidators::from_meta(&meta).map(|validators| validators.0)
+    }
+
+    #[test]
+    fn every_check() {
+        let validators = parse(parse_quote!(validate(
+            range(min = 1, max = 65535),
+            non_empty,
+            len(max = 64),
+            one_of("a", "b")
+        )))
+        .unwrap();
+
+        assert_eq!(validators.len(), 4);
+        assert!(matches!(
+            &validators[0],
+            FieldValidator::Range {
+                min: Some(_),
+                max: Some(_),
+                ..
+            }
+        ));
+        assert!(
+            matches!(&validators[3], FieldValidator::OneOf { values, .. } if values.len() == 2)
+        );
+    }
+
+    #[test]
+    fn invalid_checks() {
+        let message = |meta: syn::Meta| parse(meta).unwrap_err().to_string();
+
+        assert_eq!(
+            message(parse_quote!(validate(range()))),
+            "`range` requires `min`, `max`, or both"
+        );
+        assert_eq!(
+            message(parse_quote!(validate(len(min = 3, max = 1)))),
+            "`min` must not be greater than `max`"
+        );
+        assert_eq!(
+            message(parse_quote!(validate(range(min = 10, max = 1)))),
+            "`min` must not be greater than `max`"
+        );
+        assert_eq!(
+            message(parse_quote!(validate(range(min = -0.5, max = -1.5)))),
+            "`min` must not be greater than `max`"
+        );
+        assert!(parse(parse_quote!(validate(range(min = -10, max = 1)))).is_ok());
+        assert!(parse(parse_quote!(validate(range(min = 10, max = u8::MAX)))).is_ok());
+        assert_eq!(
+            message(parse_quote!(validate(one_of(a)))),
+            "Expected a literal"
+        );
+        assert!(message(parse_quote!(validate(between))).starts_with("Unknown field: `between`"));
+    }
+}
@@ -0,0 +1,26 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use proc_macro2::Span;
+
+/// A map that a `#[darling(multiple, key = ...)]` field can collect its items into.
+///
+/// Derived impls use the map's own lookup to find repeated keys, and keep a map of the same
+/// kind from each key to where it first appeared, so that both occurrences can be reported.
+pub trait KeyedMap {
+    /// The type of the items read from the input.
+    type Value;
+    /// A map from each key to the span of its first occurrence, and whether that occurrence
+    /// has been reported as repeated yet.
+    type Spans: Default;
+}
+
+impl<K: Ord, V> KeyedMap for BTreeMap<K, V> {
+    type Value = V;
+    type Spans = BTreeMap<K, (Span, bool)>;
+}
+
+impl<K: Hash + Eq, V, S> KeyedMap for HashMap<K, V, S> {
+    type Value = V;
+    type Spans = HashMap<K, (Span, bool)>;
+}
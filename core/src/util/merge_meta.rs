@@ -0,0 +1,167 @@
+use quote::{quote, ToTokens};
+use syn::{punctuated::Punctuated, token::Comma, Meta, MetaList};
+
+use crate::ast::NestedMeta;
+use crate::util::path_to_string;
+use crate::{Error, Result};
+
+/// Append the items of the list `other` to the list `target`, so that repeated occurrences
+/// of a key such as `http(port = 1)` and `http(host = "x")` can be parsed as one value.
+///
+/// The path of `target` is kept. Both values must be lists; a word or `name = value` item
+/// cannot be merged and produces an error spanned at the offending value.
+pub fn merge_meta(target: &mut Meta, other: &Meta) -> Result<()> {
+    let (target, other) = lists(target, other)?;
+
+    let needs_separator = match target.tokens.clone().into_iter().last() {
+        Some(proc_macro2::TokenTree::Punct(punct)) => punct.as_char() != ',',
+        Some(_) => true,
+        None => false,
+    };
+
+    if needs_separator && !other.tokens.is_empty() {
+        target.tokens.extend(quote!(,));
+    }
+
+    target.tokens.extend(other.tokens.clone());
+
+    Ok(())
+}
+
+/// Merge the list `other` into the list `target` key by key, so that `http(tls(cert = "a"))`
+/// and `http(tls(key = "b"))` become `http(tls(cert = "a", key = "b"))`.
+///
+/// `field` maps the key of an item to the index of the field which accepts it and the function
+/// which merges two occurrences of that field, or returns `None` for keys whose occurrences are
+/// kept as separate items, such as those of `multiple` fields. A list item whose field already
+/// has a list item in `target` is merged into it; every other item is appended.
+pub fn merge_meta_by_key<F>(target: &mut Meta, other: &Meta, field: F) -> Result<()>
+where
+    F: Fn(&str) -> Option<(usize, fn(&mut Meta, &Meta) -> Result<()>)>,
+{
+    let (target, other) = lists(target, other)?;
+    let mut items = NestedMeta::parse_meta_list(target.tokens.clone())?;
+    let mut errors = Error::accumulator();
+
+    for item in NestedMeta::parse_meta_list(other.tokens.clone())? {
+        if let NestedMeta::Meta(ref meta @ Meta::List(_)) = item {
+            let name = path_to_string(meta.path());
+            if let Some((index, merge)) = field(&name) {
+                let existing = items.iter_mut().find_map(|existing| match existing {
+                    NestedMeta::Meta(existing @ Meta::List(_))
+                        if field(&path_to_string(existing.path())).map(|(i, _)| i)
+                            == Some(index) =>
+                    {
+                        Some(existing)
+                    }
+                    _ => None,
+                });
+
+                if let Some(existing) = existing {
+                    errors.handle(merge(existing, meta).map_err(|e| e.at(name)));
+                    continue;
+                }
+            }
+        }
+
+        items.push(item);
+    }
+
+    target.tokens = items
+        .into_iter()
+        .collect::<Punctuated<NestedMeta, Comma>>()
+        .into_token_stream();
+
+    errors.finish()
+}
+
+/// Get the lists of `target` and `other`, or an error spanned at whichever isn't a list.
+fn lists<'a>(target: &'a mut Meta, other: &'a Meta) -> Result<(&'a mut MetaList, &'a MetaList)> {
+    let other = match other {
+        Meta::List(list) => list,
+        Meta::Path(_) | Meta::NameValue(_) => {
+            return Err(Error::custom("Only list values can be merged").with_span(other));
+        }
+    };
+
+    let target = match target {
+        Meta::List(list) => list,
+        Meta::Path(_) | Meta::NameValue(_) => {
+            return Err(Error::custom("Only list values can be merged").with_span(target));
+        }
+    };
+
+    Ok((target, other))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::{merge_meta, merge_meta_by_key};
+    use crate::Result;
+
+    #[test]
+    fn appends_items() {
+        let mut target = parse_quote!(http(port = 1));
+        merge_meta(&mut target, &parse_quote!(http(host = "x"))).unwrap();
+        merge_meta(&mut target, &parse_quote!(http())).unwrap();
+        assert_eq!(
+            target.to_token_stream().to_string(),
+            "http (port = 1 , host = \"x\")"
+        );
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let mut target = parse_quote!(http(port = 1,));
+        merge_meta(&mut target, &parse_quote!(http(tls))).unwrap();
+        assert_eq!(
+            target.to_token_stream().to_string(),
+            "http (port = 1 , tls)"
+        );
+    }
+
+    #[test]
+    fn rejects_non_lists() {
+        let mut target = parse_quote!(http(port = 1));
+        assert!(merge_meta(&mut target, &parse_quote!(http = "x")).is_err());
+    }
+
+    /// Merges the lists under `tls` and appends everything else.
+    fn by_key(target: &mut syn::Meta, other: &syn::Meta) -> Result<()> {
+        merge_meta_by_key(target, other, |name| match name {
+            "tls" => Some((
+                0,
+                merge_meta as fn(&mut syn::Meta, &syn::Meta) -> Result<()>,
+            )),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn merges_by_key() {
+        let mut target = parse_quote!(http(tls(cert = "a"), derive(Debug)));
+        by_key(
+            &mut target,
+            &parse_quote!(http(tls(key = "b"), derive(Clone), port = 1)),
+        )
+        .unwrap();
+        assert_eq!(
+            target.to_token_stream().to_string(),
+            "http (tls (cert = \"a\" , key = \"b\") , derive (Debug) , derive (Clone) , port = 1)"
+        );
+    }
+
+    #[test]
+    fn merges_by_key_into_list_only() {
+        let mut target = parse_quote!(http(tls = "x"));
+        by_key(&mut target, &parse_quote!(http(tls(key = "b")))).unwrap();
+        assert_eq!(
+            target.to_token_stream().to_string(),
+            "http (tls = \"x\" , tls (key = \"b\"))"
+        );
+        assert!(by_key(&mut target, &parse_quote!(http = "x")).is_err());
+    }
+}
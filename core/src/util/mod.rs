@@ -5,6 +5,9 @@ pub mod extract_option;
 mod flag;
 mod ident_string;
 mod ignored;
+mod keyed_map;
+mod merge_meta;
+mod names_match;
 mod over_ride;
 mod parse_attribute;
 pub mod parse_expr;
@@ -12,20 +15,28 @@ mod path_list;
 mod path_to_string;
 mod preserved_str_expr;
 mod require_ident;
+mod rest;
 mod shape;
 mod spanned_value;
+mod strip_key_affixes;
+pub mod validate;
 mod with_original;
 
 pub use self::callable::Callable;
 pub use self::flag::Flag;
 pub use self::ident_string::IdentString;
 pub use self::ignored::Ignored;
+pub use self::keyed_map::KeyedMap;
+pub use self::merge_meta::{merge_meta, merge_meta_by_key};
+pub use self::names_match::names_match;
 pub use self::over_ride::Override;
 pub use self::parse_attribute::parse_attribute_to_meta_list;
 pub use self::path_list::PathList;
 pub use self::path_to_string::path_to_string;
 pub use self::preserved_str_expr::PreservedStrExpr;
 pub use self::require_ident::require_ident;
+pub use self::rest::FromRest;
 pub use self::shape::{AsShape, Shape, ShapeSet};
 pub use self::spanned_value::SpannedValue;
+pub use self::strip_key_affixes::strip_key_affixes;
 pub use self::with_original::WithOriginal;
@@ -0,0 +1,43 @@
+/// Check whether `input` is the same name as `name` once case, `_`, and `-` are ignored, so that
+/// `ReadOnly`, `read_only`, `read-only`, and `readonly` all match each other.
+///
+/// Derived impls with `#[darling(case_insensitive)]` use this to match keys and variant names.
+pub fn names_match(input: &str, name: &str) -> bool {
+    let mut input = normalized_chars(input);
+    let mut name = normalized_chars(name);
+
+    loop {
+        match (input.next(), name.next()) {
+            (Some(a), Some(b)) if a == b => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+fn normalized_chars(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::names_match;
+
+    #[test]
+    fn spellings_match() {
+        for input in [
+            "read_only",
+            "ReadOnly",
+            "readonly",
+            "read-only",
+            "READ_ONLY",
+        ] {
+            assert!(names_match(input, "read_only"), "{}", input);
+        }
+
+        assert!(!names_match("read_write", "read_only"));
+        assert!(!names_match("read", "read_only"));
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::{BTreeMap, HashMap};
+
+use syn::Meta;
+
+use crate::ast::NestedMeta;
+use crate::{Error, Result};
+
+use super::path_to_string;
+
+/// Conversion from the meta items that no other field claimed, used to populate
+/// fields marked `#[darling(rest)]`.
+///
+/// # Usage
+/// A `rest` field makes it possible to forward unknown options to another attribute verbatim:
+///
+/// ```rust,ignore
+/// #[derive(FromMeta)]
+/// struct Options {
+///     name: String,
+///     #[darling(rest)]
+///     passthrough: Vec<NestedMeta>,
+/// }
+/// ```
+///
+/// Given `#[my_attr(name = "x", skip_serializing, rename = "y")]`, the `passthrough` field
+/// holds `skip_serializing` and `rename = "y"`, in the order they appeared.
+pub trait FromRest: Sized {
+    /// Create an instance from the unclaimed items, in the order they appeared in the input.
+    fn from_rest(items: Vec<NestedMeta>) -> Result<Self>;
+}
+
+impl FromRest for Vec<NestedMeta> {
+    fn from_rest(items: Vec<NestedMeta>) -> Result<Self> {
+        Ok(items)
+    }
+}
+
+/// Key each unclaimed item by its path. Literals have no key, so they are rejected,
+/// as are keys that appear more than once.
+fn keyed_items(items: Vec<NestedMeta>) -> Result<Vec<(String, Meta)>> {
+    let mut errors = Error::accumulator();
+    let mut seen = Vec::with_capacity(items.len());
+
+    for item in items {
+        match item {
+            NestedMeta::Meta(meta) => {
+                let key = path_to_string(meta.path());
+                if seen.iter().any(|(existing, _)| *existing == key) {
+                    errors.push(Error::duplicate_field(&key).with_span(&meta));
+                } else {
+                    seen.push((key, meta));
+                }
+            }
+            NestedMeta::Lit(lit) => {
+                errors.push(Error::unsupported_format("literal").with_span(&lit));
+            }
+        }
+    }
+
+    errors.finish_with(seen)
+}
+
+impl FromRest for BTreeMap<String, Meta> {
+    fn from_rest(items: Vec<NestedMeta>) -> Result<Self> {
+        keyed_items(items).map(|items| items.into_iter().collect())
+    }
+}
+
+impl<S: std::hash::BuildHasher + Default> FromRest for HashMap<String, Meta, S> {
+    fn from_rest(items: Vec<NestedMeta>) -> Result<Self> {
+        keyed_items(items).map(|items| items.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use syn::parse_quote;
+
+    use super::FromRest;
+    use crate::ast::NestedMeta;
+
+    #[test]
+    fn vec_keeps_order() {
+        let items: Vec<NestedMeta> =
+            vec![parse_quote!(b = 1), parse_quote!("lit"), parse_quote!(a)];
+        assert_eq!(Vec::<NestedMeta>::from_rest(items.clone()).unwrap(), items);
+    }
+
+    #[test]
+    fn map_by_path() {
+        let map = BTreeMap::<String, syn::Meta>::from_rest(vec![
+            parse_quote!(b = 1),
+            parse_quote!(a::b(c)),
+        ])
+        .unwrap();
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["a::b", "b"]);
+    }
+
+    #[test]
+    fn map_rejects_duplicates_and_literals() {
+        let err = BTreeMap::<String, syn::Meta>::from_rest(vec![
+            parse_quote!(a),
+            parse_quote!(a = 2),
+            parse_quote!("lit"),
+        ])
+        .unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+}
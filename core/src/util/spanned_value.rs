@@ -2,6 +2,7 @@ use proc_macro2::Span;
 use std::ops::{Deref, DerefMut};
 use syn::spanned::Spanned;
 
+use crate::error::Warning;
 use crate::{
     FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta, FromTypeParam,
     FromVariant, Result,
@@ -87,7 +88,11 @@ macro_rules! spanned {
 
 impl<T: FromMeta> FromMeta for SpannedValue<T> {
     fn from_meta(item: &syn::Meta) -> Result<Self> {
-        let value = T::from_meta(item).map_err(|e| e.with_span(item))?;
+        Self::from_meta_with_warnings(item, &mut Vec::new())
+    }
+
+    fn from_meta_with_warnings(item: &syn::Meta, warnings: &mut Vec<Warning>) -> Result<Self> {
+        let value = T::from_meta_with_warnings(item, warnings).map_err(|e| e.with_span(item))?;
         let span = match item {
             // Example: `#[darling(skip)]` as SpannedValue<bool>
             // should have the span pointing to the word `skip`.
@@ -103,6 +108,24 @@ impl<T: FromMeta> FromMeta for SpannedValue<T> {
         Ok(Self::new(value, span))
     }
 
+    fn field_names() -> Option<Vec<String>> {
+        T::field_names()
+    }
+
+    fn accepts_field(name: &str) -> bool {
+        T::accepts_field(name)
+    }
+
+    // A flattened value has no node of its own, so it points at the first item it received.
+    fn from_flattened(items: &[crate::ast::NestedMeta]) -> Result<Self> {
+        let span = items.first().map_or_else(Span::call_site, Spanned::span);
+        T::from_flattened(items).map(|value| Self::new(value, span))
+    }
+
+    fn merge_duplicate(target: &mut syn::Meta, other: &syn::Meta) -> Result<()> {
+        T::merge_duplicate(target, other)
+    }
+
     fn from_nested_meta(item: &crate::ast::NestedMeta) -> Result<Self> {
         T::from_nested_meta(item)
             .map(|value| Self::new(value, item.span()))
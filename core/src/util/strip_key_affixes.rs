@@ -0,0 +1,51 @@
+use syn::{Ident, Meta};
+
+use crate::ast::NestedMeta;
+
+/// If the key of `item` is an identifier which starts with `prefix` and ends with `suffix`,
+/// return a copy of `item` with both removed from its key.
+///
+/// The new key keeps the span of the original. `flatten(prefix = "...")` fields in derived
+/// trait implementations use this to present `http_timeout` to the flattened type as `timeout`.
+pub fn strip_key_affixes(item: &NestedMeta, prefix: &str, suffix: &str) -> Option<NestedMeta> {
+    let mut meta = match item {
+        NestedMeta::Meta(meta) => meta.clone(),
+        NestedMeta::Lit(_) => return None,
+    };
+
+    let path = match &mut meta {
+        Meta::Path(path) => path,
+        Meta::List(list) => &mut list.path,
+        Meta::NameValue(nv) => &mut nv.path,
+    };
+
+    let ident = path.get_ident()?;
+    let key = ident.to_string();
+    let stripped = key.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    let mut stripped: Ident = syn::parse_str(stripped).ok()?;
+    stripped.set_span(ident.span());
+    *path = stripped.into();
+
+    Some(NestedMeta::Meta(meta))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::strip_key_affixes;
+
+    #[test]
+    fn strips_both() {
+        let item = strip_key_affixes(&parse_quote!(http_port_v2 = 80), "http_", "_v2").unwrap();
+        assert_eq!(item.to_token_stream().to_string(), "port = 80");
+    }
+
+    #[test]
+    fn requires_affixes() {
+        assert!(strip_key_affixes(&parse_quote!(grpc_port = 80), "http_", "").is_none());
+        assert!(strip_key_affixes(&parse_quote!(http_ = 80), "http_", "").is_none());
+        assert!(strip_key_affixes(&parse_quote!("http_port"), "http_", "").is_none());
+    }
+}
@@ -0,0 +1,225 @@
+//! Checks behind field-level `#[darling(validate(...))]`.
+//!
+//! Derived impls call these functions on each value read for a field. The errors they return
+//! have no span or location; the caller adds both. Each check passes for a value with nothing
+//! to check, such as `None`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Display};
+
+use crate::util::SpannedValue;
+use crate::{Error, Result};
+
+/// A field type whose values can be checked by `#[darling(validate(...))]`.
+///
+/// Wrapper types such as `Option<T>` and `SpannedValue<T>` forward to the value they hold.
+pub trait Validate {
+    /// The type which is compared against bounds and listed values.
+    type Value: ?Sized;
+
+    /// Get the value to check, or `None` if there is nothing to check.
+    fn validated(&self) -> Option<&Self::Value>;
+}
+
+/// A value with a length which `non_empty` and `len` can check.
+pub trait Length {
+    /// The number of characters or items in the value.
+    fn length(&self) -> usize;
+}
+
+macro_rules! validate_self {
+    ($($ty:ty),*) => {
+        $(
+            impl Validate for $ty {
+                type Value = Self;
+
+                fn validated(&self) -> Option<&Self> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+validate_self!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
+);
+
+macro_rules! validate_collection {
+    ($($ty:ident<$($param:ident),*>),*) => {
+        $(
+            impl<$($param),*> Validate for $ty<$($param),*> {
+                type Value = Self;
+
+                fn validated(&self) -> Option<&Self> {
+                    Some(self)
+                }
+            }
+
+            impl<$($param),*> Length for $ty<$($param),*> {
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
+validate_collection!(Vec<T>, BTreeSet<T>, HashSet<T, S>, BTreeMap<K, V>, HashMap<K, V, S>);
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    type Value = T::Value;
+
+    fn validated(&self) -> Option<&Self::Value> {
+        self.as_ref().and_then(T::validated)
+    }
+}
+
+impl<T: Validate> Validate for SpannedValue<T> {
+    type Value = T::Value;
+
+    fn validated(&self) -> Option<&Self::Value> {
+        (**self).validated()
+    }
+}
+
+/// Check that `value` is no less than `min` and no greater than `max`.
+pub fn range<T, B>(value: &T, min: Option<B>, max: Option<B>) -> Result<()>
+where
+    T: Validate + ?Sized,
+    T::Value: PartialOrd<B>,
+    B: Display,
+{
+    let value = match value.validated() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    let below = min.as_ref().map_or(false, |min| *value < *min);
+    let above = max.as_ref().map_or(false, |max| *value > *max);
+    if below || above {
+        Err(Error::custom(format!("must be {}", Bounds(min, max))))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that `value` has at least one character or item.
+pub fn non_empty<T>(value: &T) -> Result<()>
+where
+    T: Validate + ?Sized,
+    T::Value: Length,
+{
+    match value.validated() {
+        Some(value) if value.length() == 0 => Err(Error::custom("must not be empty")),
+        _ => Ok(()),
+    }
+}
+
+/// Check that the number of characters or items in `value` is no less than `min` and no
+/// greater than `max`.
+pub fn len<T>(value: &T, min: Option<usize>, max: Option<usize>) -> Result<()>
+where
+    T: Validate + ?Sized,
+    T::Value: Length,
+{
+    let len = match value.validated() {
+        Some(value) => value.length(),
+        None => return Ok(()),
+    };
+
+    if min.map_or(false, |min| len < min) || max.map_or(false, |max| len > max) {
+        Err(Error::custom(format!(
+            "length must be {}, but is {}",
+            Bounds(min, max),
+            len
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that `value` is equal to one of `allowed`.
+pub fn one_of<T, B>(value: &T, allowed: &[B]) -> Result<()>
+where
+    T: Validate + ?Sized,
+    T::Value: PartialEq<B>,
+    B: Display,
+{
+    match value.validated() {
+        Some(value) if !allowed.iter().any(|item| *value == *item) => {
+            let allowed = allowed
+                .iter()
+                .map(|item| format!("`{}`", item))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(Error::custom(format!("must be one of {}", allowed)))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Describes bounds, such as "at least 1" or "between 1 and 10".
+struct Bounds<B>(Option<B>, Option<B>);
+
+impl<B: Display> Display for Bounds<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.0, &self.1) {
+            (Some(min), Some(max)) => write!(f, "between {} and {}", min, max),
+            (Some(min), None) => write!(f, "at least {}", min),
+            (None, Some(max)) => write!(f, "at most {}", max),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{len, non_empty, one_of, range};
+
+    #[test]
+    fn range_messages() {
+        assert!(range(&5u16, Some(1), Some(10)).is_ok());
+        assert_eq!(
+            range(&0u16, Some(1), Some(10)).unwrap_err().to_string(),
+            "must be between 1 and 10"
+        );
+        assert_eq!(
+            range(&-1.5f64, Some(0.0), None).unwrap_err().to_string(),
+            "must be at least 0"
+        );
+        assert!(range(&None::<u8>, Some(1), None).is_ok());
+    }
+
+    #[test]
+    fn length_checks() {
+        assert_eq!(
+            non_empty(&String::new()).unwrap_err().to_string(),
+            "must not be empty"
+        );
+        assert!(non_empty(&vec![1]).is_ok());
+        assert_eq!(
+            len(&Some(String::from("abcd")), None, Some(3))
+                .unwrap_err()
+                .to_string(),
+            "length must be at most 3, but is 4"
+        );
+    }
+
+    #[test]
+    fn one_of_message() {
+        assert!(one_of(&String::from("a"), &["a", "b"]).is_ok());
+        assert_eq!(
+            one_of(&String::from("c"), &["a", "b"])
+                .unwrap_err()
+                .to_string(),
+            "must be one of `a`, `b`"
+        );
+    }
+}
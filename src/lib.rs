@@ -17,15 +17,38 @@
 //!
 //! * **Field renaming**: You can use `#[darling(rename="new_name")]` on a field to change the name Darling looks for.
 //!   You can also use `#[darling(rename_all="...")]` at the struct or enum level to apply a casing rule to all fields or variants.
+//!   On an enum, `#[darling(rename_all_fields="...")]` or a variant-level `rename_all` gives the fields of variants their own rule.
+//!   `rename_all(strip_prefix="...", strip_suffix="...", case="...")` removes affixes before applying the rule, and
+//!   `rename_all=path::to::fn` names each field or variant with a `fn(&str) -> String`, which is called when parsing.
+//! * **Case-insensitive matching**: You can use `#[darling(case_insensitive)]` on a container, variant, or field to match
+//!   names regardless of case, `_`, and `-`, so `read_only`, `ReadOnly`, and `readonly` all select the same variant.
+//! * **Aliases**: You can use `#[darling(alias="old_name")]` on a field or variant to also accept another name in the input.
+//!   This can be repeated; all aliases are treated as the same key as the field's primary name.
 //! * **Map function**: You can use `#[darling(map="path::to::function")]` to run code on a field before it's stored in the struct.
 //! * **Default values**: You can use `#[darling(default)]` at the type or field level to use that type's default value to fill
 //!   in values not specified by the caller. You can also set a custom default value by passing in a function path or a closure:
 //!   `#[darling(default = path::to::function)]` or `#[darling(default = || get_default())]`.
+//!   For a literal, use `#[darling(default_value = 8080)]`, which is checked against primitive and `String` field types when deriving.
+//!   To compute a default from other fields, use `#[darling(default_from(tls, with = |tls: &bool| if *tls { 443 } else { 80 }))]`.
+//! * **Deprecation**: You can use `#[darling(deprecated)]` or `#[darling(deprecated = "use `foo` instead")]` on a field or
+//!   variant. Parsing still succeeds when it is used, but an [`error::Warning`] pointing at it is added to the
+//!   `Vec<Warning>` field marked `#[darling(warnings)]`, or to the list passed to [`FromMeta::from_meta_with_warnings`].
+//! * **Positional arguments**: You can use `#[darling(positional)]` on the leading fields of a struct to fill them,
+//!   in order, from literals or bare paths that appear before any named items, e.g. `#[route("/users", method = "GET")]`.
+//! * **Field constraints**: You can use `#[darling(conflicts_with = "other")]` or `#[darling(requires = "other")]` on
+//!   a field to declare that it must not, or must, appear alongside another field. Use `#[darling(one_of(a, b))]` on a struct
+//!   to require that exactly one of the listed fields is set. Errors point at the keys the caller wrote.
+//! * **Validation**: You can use `#[darling(validate = Self::validate)]` on a struct to check the parsed value with a
+//!   `fn(&Self, &mut error::Accumulator)`. Errors the function reports at a field, e.g. with `Error::at("port")`,
+//!   point at the key the caller wrote for that field.
+//! * **Value validators**: You can use `#[darling(validate(range(min = 1), non_empty, len(max = 64), one_of("a", "b")))]`
+//!   on a field to check each value read for it. The checks live in [`util::validate`].
 //! * **Skipped fields**: You can skip a variant or field using `#[darling(skip)]`. Fields marked with this will fall back to
 //!   `Default::default()` for their value, but you can override that with an explicit default or a value from the type-level default.
 //! * **Custom shorthand**: Use `#[darling(from_word = ...)]` on a struct or enum to override how a simple word is interpreted.
 //!   By default, it is an error for your macro's user to fail to specify the fields of your struct, but with this you can choose to
 //!   instead produce a set of default values. This takes either a path or a closure whose signature matches `FromMeta::from_word`.
+//!   On a field, `#[darling(from_word = ...)]` and `#[darling(from_expr = ...)]` override how that field alone is read.
 //! * **Custom handling for missing fields**: When a field is not present and `#[darling(default)]` is not used, derived impls will
 //!   call `FromMeta::from_none` on that field's type to try and get the fallback value for the field. Usually, there is not a fallback
 //!   value, so a missing field error is generated. `Option<T: FromMeta>` uses this to make options optional without requiring
@@ -33,11 +56,23 @@
 //!   This takes either a path or a closure whose signature matches `FromMeta::from_none`.
 //! * **Generate [`syn::parse::Parse`] impl**: When deriving `FromMeta`, add `#[darling(derive_syn_parse)]` to also generate an impl
 //!   of the `Parse` trait.
+//! * **Internally tagged enums**: When deriving `FromMeta` for an enum, add `#[darling(tag = "kind")]` to select the variant
+//!   using a key in the same list as the variant's fields, e.g. `source(kind = "file", path = "x")`.
+//! * **Untagged enums**: When deriving `FromMeta` for an enum, add `#[darling(untagged)]` to try each variant in declaration
+//!   order against the whole input and use the first that succeeds, e.g. to accept `timeout = 30` or `timeout(secs = 30)`.
+//! * **Catch-all variants**: When deriving `FromMeta` for an enum, add `#[darling(other)]` to one variant with a single unnamed
+//!   field, such as `Custom(String)`, to receive unknown words and strings instead of producing an error.
+//! * **Unclaimed items**: Add `#[darling(rest)]` to a field of type `Vec<NestedMeta>` or `BTreeMap<String, syn::Meta>`
+//!   to collect every item that no other field claimed, such as options to forward to another attribute.
+//! * **Duplicate keys**: Add `#[darling(on_duplicate = "first")]`, `"last"`, or `"merge"` to a field or container to
+//!   accept repeated keys; `merge` combines the list values of every occurrence, e.g. from separate attributes.
 //! * **Renamed crate**: You can use `#[darling(crate = ...)]` to specify a path to the `darling` crate
 //!   instance to use when referring to `darling` from generated code.
 //! * **Transparency**: Tuple structs or tuple variants with a single field delegate trait implementations
 //!   to the inner type. If the field is named, you can use `#[darling(transparent)]` on the container for
 //!   the same effect
+//! * **Tuples**: Tuple structs or tuple variants with several fields read them in order from a list, so
+//!   `struct Point(u32, u32)` parses `point(1, 2)`.
 //!
 //! ## Forwarded Fields
 //! All derivable traits except `FromMeta` support forwarding some fields from the input AST to the derived struct.
@@ -118,7 +153,7 @@ pub mod export {
     pub use darling_core::syn;
     pub use std::clone::Clone;
     pub use std::marker::PhantomData;
-    pub use std::string::ToString;
+    pub use std::string::{String, ToString};
     pub use std::vec::Vec;
 
     pub use crate::ast::NestedMeta;
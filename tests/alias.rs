@@ -0,0 +1,109 @@
+//! Test that `#[darling(alias = "...")]` allows fields and variants to be
+//! matched by additional names.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    #[darling(alias = "ro")]
+    ReadOnly,
+    ReadWrite,
+    #[darling(alias = "attach")]
+    Mounted {
+        path: String,
+    },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(alias))]
+struct Receiver {
+    #[darling(alias = "old_name", alias = "older_name")]
+    name: String,
+    #[darling(default, alias = "access")]
+    mode: Option<Mode>,
+}
+
+#[test]
+fn primary_name() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[alias(name = "example")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.name, "example");
+}
+
+#[test]
+fn field_alias() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[alias(older_name = "example", access = "ro")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.name, "example");
+    assert_eq!(parsed.mode, Some(Mode::ReadOnly));
+}
+
+#[test]
+fn variant_alias_in_list() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[alias(name = "example", mode(attach(path = "/mnt")))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        parsed.mode,
+        Some(Mode::Mounted {
+            path: "/mnt".to_string()
+        })
+    );
+}
+
+#[test]
+fn alias_and_primary_are_duplicates() {
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[alias(name = "example", old_name = "other")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+    assert!(err.to_string().contains("Duplicate field `name`"));
+}
+
+#[test]
+fn aliases_are_duplicates_across_attributes() {
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[alias(old_name = "example")]
+        #[alias(older_name = "other")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+}
+
+#[test]
+fn unknown_field_lists_aliases() {
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[alias(name = "example", zzz = 1)]
+        struct Demo;
+    })
+    .unwrap_err()
+    .to_string();
+
+    assert!(err.contains("`old_name`"));
+    assert!(err.contains("`older_name`"));
+    assert!(err.contains("`access`"));
+}
+
+#[test]
+fn unknown_value_lists_aliases() {
+    let err = Mode::from_string("zzz").unwrap_err().to_string();
+    assert!(err.contains("`ro`"));
+    assert!(err.contains("`read_only`"));
+}
@@ -0,0 +1,101 @@
+//! Test that `#[darling(case_insensitive)]` matches keys and variant names regardless of case,
+//! `_`, and `-`.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(case_insensitive)]
+enum Mode {
+    ReadOnly,
+    ReadWrite,
+    Custom { level: u8 },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(store), case_insensitive)]
+struct Store {
+    mode: Mode,
+    #[darling(default)]
+    max_size: Option<u32>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Partial {
+    #[darling(case_insensitive, default)]
+    cache_dir: Option<String>,
+    #[darling(default)]
+    log_level: Option<String>,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(case_insensitive)]
+struct Logging {
+    log_level: Option<String>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Retry {
+    attempts: Option<u32>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Service {
+    #[darling(flatten)]
+    logging: Logging,
+    #[darling(flatten)]
+    retry: Retry,
+}
+
+#[test]
+fn enum_value_spellings() {
+    for value in ["read_only", "ReadOnly", "readonly", "read-only"] {
+        assert_eq!(
+            Mode::from_string(value).unwrap(),
+            Mode::ReadOnly,
+            "{}",
+            value
+        );
+    }
+
+    assert!(Mode::from_string("read").is_err());
+}
+
+#[test]
+fn key_spellings() {
+    let store = Store::from_derive_input(&parse_quote! {
+        #[store(Mode = "read-write", MaxSize = 10)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(store.mode, Mode::ReadWrite);
+    assert_eq!(store.max_size, Some(10));
+}
+
+#[test]
+fn variant_and_field_keys() {
+    let mode = Mode::from_list(&[parse_quote!(CUSTOM(Level = 3))]).unwrap();
+    assert_eq!(mode, Mode::Custom { level: 3 });
+}
+
+#[test]
+fn field_level() {
+    let partial = Partial::from_list(&[parse_quote!(cacheDir = "/tmp")]).unwrap();
+    assert_eq!(partial.cache_dir.as_deref(), Some("/tmp"));
+    assert_eq!(partial.log_level, None);
+
+    let err = Partial::from_list(&[parse_quote!(logLevel = "debug")]).unwrap_err();
+    assert!(err.to_string().starts_with("Unknown field: `logLevel`"));
+}
+
+#[test]
+fn flattened_keys() {
+    assert!(Logging::accepts_field("LogLevel"));
+    assert!(!Retry::accepts_field("Attempts"));
+
+    let service =
+        Service::from_list(&[parse_quote!(LogLevel = "x"), parse_quote!(attempts = 2)]).unwrap();
+    assert_eq!(service.logging.log_level.as_deref(), Some("x"));
+    assert_eq!(service.retry.attempts, Some(2));
+}
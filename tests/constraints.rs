@@ -0,0 +1,157 @@
+//! Test `conflicts_with`, `requires`, and `one_of` cross-field constraints.
+#![allow(dead_code)]
+
+use darling::{util::Flag, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+#[darling(one_of(path, url, inline))]
+struct Source {
+    path: Option<String>,
+    url: Option<String>,
+    inline: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(endpoint), one_of(path, url))]
+struct Endpoint {
+    path: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    #[darling(conflicts_with = "secure")]
+    insecure: Flag,
+    secure: Flag,
+    #[darling(requires = "tls_key")]
+    tls_cert: Option<String>,
+    tls_key: Option<String>,
+    #[darling(default)]
+    source: Option<Source>,
+}
+
+fn parse(di: syn::DeriveInput) -> darling::Result<Server> {
+    Server::from_derive_input(&di)
+}
+
+#[test]
+fn no_constraints_violated() {
+    let server = parse(parse_quote! {
+        #[server(secure, tls_cert = "a.pem", tls_key = "a.key", source(url = "x"))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(server.secure.is_present());
+    assert_eq!(server.source.unwrap().url.as_deref(), Some("x"));
+}
+
+#[test]
+fn conflict() {
+    let err = parse(parse_quote! {
+        #[server(insecure, secure)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    // One error is reported on each of the offending keys.
+    assert_eq!(err.len(), 2);
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "`insecure` cannot be used together with `secure`",
+            "`secure` cannot be used together with `insecure`",
+        ]
+    );
+}
+
+#[test]
+fn conflict_across_attributes() {
+    let err = parse(parse_quote! {
+        #[server(insecure)]
+        #[server(secure)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 2);
+}
+
+#[test]
+fn missing_requirement() {
+    let err = parse(parse_quote! {
+        #[server(tls_cert = "a.pem")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+    assert_eq!(
+        err.to_string(),
+        "`tls_cert` requires `tls_key` to also be set"
+    );
+}
+
+#[test]
+fn requirement_is_one_way() {
+    parse(parse_quote! {
+        #[server(tls_key = "a.key")]
+        struct Demo;
+    })
+    .unwrap();
+}
+
+#[test]
+fn one_of_none() {
+    let err = Source::from_list(&[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Exactly one of `path`, `url`, `inline` must be set"
+    );
+}
+
+#[test]
+fn one_of_none_is_spanned() {
+    let err = Endpoint::from_derive_input(&parse_quote! {
+        #[endpoint()]
+        struct Demo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Exactly one of `path`, `url` must be set");
+    assert!(err.has_span());
+
+    let err = Source::from_meta(&parse_quote!(source())).unwrap_err();
+    assert!(err.has_span());
+}
+
+#[test]
+fn one_of_many() {
+    let err = parse(parse_quote! {
+        #[server(source(path = "a", inline = "b"))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 2);
+    assert!(err.into_iter().all(|e| e
+        .to_string()
+        .starts_with("Only one of `path`, `url`, `inline` may be set")));
+}
+
+#[test]
+fn struct_variant_fields() {
+    #[derive(Debug, FromMeta)]
+    enum Auth {
+        Basic {
+            #[darling(conflicts_with = "token")]
+            user: Option<String>,
+            token: Option<String>,
+        },
+    }
+
+    let err = Auth::from_list(&[parse_quote!(basic(user = "a", token = "b"))]).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
@@ -0,0 +1,93 @@
+//! Test that `#[darling(default_from(...))]` computes a fallback from other fields' values.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+fn default_host() -> String {
+    "localhost".into()
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    #[darling(default)]
+    tls: bool,
+    #[darling(default_from(tls, with = |tls: &bool| if *tls { 443 } else { 80 }))]
+    port: u16,
+    #[darling(default = default_host)]
+    host: String,
+    #[darling(default_from(host, port, with = |host: &String, port: &u16| format!("{}:{}", host, port)))]
+    address: String,
+}
+
+#[derive(Debug, FromMeta)]
+enum Listener {
+    Tcp {
+        #[darling(default)]
+        tls: bool,
+        #[darling(default_from(tls, with = |tls: &bool| if *tls { 443 } else { 80 }))]
+        port: u16,
+    },
+}
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct Inherited {
+    name: String,
+    #[darling(default_from(name, with = |name: &String| name.len()))]
+    len: usize,
+}
+
+#[test]
+fn depends_on_explicit_field() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(tls)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(server.tls);
+    assert_eq!(server.port, 443);
+    assert_eq!(server.address, "localhost:443");
+}
+
+#[test]
+fn depends_on_defaults() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server()]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.port, 80);
+    assert_eq!(server.address, "localhost:80");
+}
+
+#[test]
+fn explicit_value_wins() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(tls, port = 8443, host = "example.com")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.port, 8443);
+    assert_eq!(server.host, "example.com");
+    assert_eq!(server.address, "example.com:8443");
+}
+
+#[test]
+fn enum_variant_fields() {
+    let Listener::Tcp { tls, port } = Listener::from_list(&[parse_quote!(tcp(tls))]).unwrap();
+    assert!(tls);
+    assert_eq!(port, 443);
+}
+
+#[test]
+fn reads_inherited_default() {
+    let inherited = Inherited::from_list(&[parse_quote!(name = "abc")]).unwrap();
+    assert_eq!(inherited.len, 3);
+
+    let inherited = Inherited::from_list(&[]).unwrap();
+    assert_eq!(inherited.len, 0);
+}
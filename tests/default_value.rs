@@ -0,0 +1,77 @@
+//! Test that `#[darling(default_value = ...)]` provides a field's fallback from a literal.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Level {
+    Info,
+    Debug,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    #[darling(default_value = 8080)]
+    port: u16,
+    #[darling(default_value = "localhost")]
+    host: String,
+    #[darling(default_value = -1.5)]
+    offset: f64,
+    #[darling(default_value = true)]
+    enabled: Option<bool>,
+    #[darling(default_value = "debug")]
+    level: Level,
+    #[darling(default_value = 60 * 60)]
+    ttl: u32,
+    #[darling(default_value = "30")]
+    timeout: u64,
+}
+
+#[derive(Debug, FromMeta)]
+struct BadDefault {
+    #[darling(default_value = "bogus")]
+    level: Level,
+}
+
+#[test]
+fn fallbacks_when_missing() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server()]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.offset, -1.5);
+    assert_eq!(server.enabled, Some(true));
+    assert_eq!(server.level, Level::Debug);
+    assert_eq!(server.ttl, 3600);
+    assert_eq!(server.timeout, 30);
+}
+
+#[test]
+fn input_overrides_default() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(port = 80, host = "example.com", level = "info", enabled = false)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.port, 80);
+    assert_eq!(server.host, "example.com");
+    assert_eq!(server.level, Level::Info);
+    assert_eq!(server.enabled, Some(false));
+}
+
+#[test]
+fn bad_default_is_an_error() {
+    let err = BadDefault::from_list(&[]).unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("Unknown value: `bogus`"), "{}", message);
+    assert!(message.ends_with(" at level"), "{}", message);
+
+    let ok = BadDefault::from_list(&[parse_quote!(level = "info")]).unwrap();
+    assert_eq!(ok.level, Level::Info);
+}
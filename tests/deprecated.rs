@@ -0,0 +1,240 @@
+//! Test that `#[darling(deprecated)]` fields and variants parse successfully
+//! while reporting a warning.
+
+use darling::{error::Warning, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    #[darling(deprecated = "use `read_only` instead")]
+    Ro,
+    ReadOnly,
+    #[darling(deprecated)]
+    Mounted {
+        path: String,
+    },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(dep))]
+struct Receiver {
+    #[darling(default, deprecated = "use `name` instead", alias = "title")]
+    label: Option<String>,
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(default)]
+    mode: Option<Mode>,
+    #[darling(default)]
+    nested: Option<Nested>,
+    #[darling(warnings)]
+    warnings: Vec<Warning>,
+}
+
+fn upper(name: &str) -> String {
+    name.to_uppercase()
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(dep), rename_all = upper)]
+struct Computed {
+    #[darling(default, deprecated = "use `NAME` instead")]
+    old_name: Option<String>,
+    #[darling(warnings)]
+    warnings: Vec<Warning>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Nested {
+    #[darling(default, deprecated)]
+    legacy: bool,
+    #[darling(default)]
+    mode: Option<Mode>,
+}
+
+#[test]
+fn no_warning_when_absent() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(name = "example", mode = "read_only")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.name.as_deref(), Some("example"));
+    assert!(parsed.warnings.is_empty());
+}
+
+#[test]
+fn deprecated_field() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(label = "example")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.label.as_deref(), Some("example"));
+    assert_eq!(parsed.warnings.len(), 1);
+    assert_eq!(
+        parsed.warnings[0].message(),
+        "use of deprecated field `label`: use `name` instead"
+    );
+    assert!(parsed.warnings[0].explicit_span().is_some());
+}
+
+#[test]
+fn deprecated_field_in_nested_value() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(nested(legacy, mode(mounted(path = "/mnt"))))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(parsed.nested.unwrap().legacy);
+    let messages = parsed
+        .warnings
+        .iter()
+        .map(Warning::message)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "use of deprecated field `legacy`",
+            "use of deprecated variant `mounted`",
+        ]
+    );
+}
+
+#[test]
+fn deprecated_field_by_alias() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(title = "example")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.label.as_deref(), Some("example"));
+    assert_eq!(parsed.warnings.len(), 1);
+}
+
+#[test]
+fn deprecated_field_with_computed_name() {
+    let parsed = Computed::from_derive_input(&parse_quote! {
+        #[dep(OLD_NAME = "example")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.old_name.as_deref(), Some("example"));
+    assert_eq!(parsed.warnings.len(), 1);
+    assert_eq!(
+        parsed.warnings[0].message(),
+        "use of deprecated field `OLD_NAME`: use `NAME` instead"
+    );
+}
+
+#[test]
+fn deprecated_unit_variant() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(mode = "ro")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.mode, Some(Mode::Ro));
+    assert_eq!(parsed.warnings.len(), 1);
+    assert_eq!(
+        parsed.warnings[0].message(),
+        "use of deprecated variant `ro`: use `read_only` instead"
+    );
+    assert!(parsed.warnings[0].explicit_span().is_some());
+}
+
+#[test]
+fn deprecated_unit_variant_in_list() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(mode(ro))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.mode, Some(Mode::Ro));
+    assert_eq!(parsed.warnings.len(), 1);
+}
+
+#[test]
+fn deprecated_struct_variant() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(mode(mounted(path = "/mnt")))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        parsed.mode,
+        Some(Mode::Mounted {
+            path: "/mnt".into()
+        })
+    );
+    assert_eq!(parsed.warnings.len(), 1);
+    assert_eq!(
+        parsed.warnings[0].message(),
+        "use of deprecated variant `mounted`"
+    );
+    assert!(parsed.warnings[0].explicit_span().is_some());
+}
+
+#[test]
+fn from_meta_with_warnings() {
+    let mut warnings = Vec::new();
+    let parsed =
+        Nested::from_meta_with_warnings(&parse_quote!(nested(legacy, mode = "ro")), &mut warnings)
+            .unwrap();
+
+    assert!(parsed.legacy);
+    assert_eq!(parsed.mode, Some(Mode::Ro));
+    let messages = warnings.iter().map(Warning::message).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "use of deprecated field `legacy`",
+            "use of deprecated variant `ro`: use `read_only` instead",
+        ]
+    );
+}
+
+#[test]
+fn from_meta_drops_warnings() {
+    let parsed = Nested::from_meta(&parse_quote!(nested(legacy))).unwrap();
+
+    assert!(parsed.legacy);
+}
+
+#[test]
+fn warnings_alongside_errors() {
+    let mut warnings = Vec::new();
+    let parsed = Nested::from_list_with_warnings(
+        &darling::ast::NestedMeta::parse_meta_list(quote::quote!(legacy, unknown)).unwrap(),
+        &mut warnings,
+    );
+
+    assert!(parsed.is_err());
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+fn write_warning() {
+    let parsed = Receiver::from_derive_input(&parse_quote! {
+        #[dep(label = "example")]
+        struct Demo;
+    })
+    .unwrap();
+
+    let tokens = parsed
+        .warnings
+        .into_iter()
+        .map(Warning::write_warning)
+        .collect::<proc_macro2::TokenStream>()
+        .to_string();
+
+    assert!(tokens.contains("deprecated"));
+    assert!(tokens.contains("use of deprecated field `label`: use `name` instead"));
+}
@@ -0,0 +1,85 @@
+//! Test that dotted keys such as `http.port = 8080` address fields of nested receivers.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta)]
+struct Tls {
+    cert: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta)]
+struct Http {
+    port: Option<u16>,
+    #[darling(default)]
+    tls: bool,
+    #[darling(default)]
+    certs: Tls,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    name: Option<String>,
+    #[darling(default)]
+    http: Http,
+}
+
+#[test]
+fn shorthand_for_nesting() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(http.port = 8080, name = "api", http.tls = true)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.name.as_deref(), Some("api"));
+    assert_eq!(
+        server.http,
+        Http {
+            port: Some(8080),
+            tls: true,
+            certs: Tls::default(),
+        }
+    );
+}
+
+#[test]
+fn several_levels() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(http.certs.cert = "a.pem", http.tls)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(server.http.tls);
+    assert_eq!(server.http.certs.cert.as_deref(), Some("a.pem"));
+}
+
+#[test]
+fn both_forms_conflict() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(http(port = 8080), http.tls = true)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "`http` cannot be set both directly and with dotted keys"
+    );
+}
+
+#[test]
+fn errors_located_in_nested_receiver() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(http.port = 1, http.prot = 2)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `prot`. Did you mean `port`? at http"
+    );
+}
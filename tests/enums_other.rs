@@ -0,0 +1,118 @@
+//! Test that a `#[darling(other)]` variant receives values that don't match any other variant.
+
+use darling::{util::SpannedValue, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Encoding {
+    Utf8,
+    Latin1,
+    #[darling(other)]
+    Custom(String),
+}
+
+#[derive(Debug, FromMeta)]
+enum Level {
+    Low,
+    #[darling(other)]
+    Other(SpannedValue<String>),
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(io))]
+struct Receiver {
+    encoding: Option<Encoding>,
+    level: Option<Level>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(io))]
+struct Encodings {
+    #[darling(multiple)]
+    encoding: Vec<Encoding>,
+}
+
+#[test]
+fn known_value() {
+    assert_eq!(Encoding::from_string("utf8").unwrap(), Encoding::Utf8);
+}
+
+#[test]
+fn unknown_string() {
+    assert_eq!(
+        Encoding::from_string("utf16").unwrap(),
+        Encoding::Custom("utf16".into())
+    );
+}
+
+#[test]
+fn unknown_value_in_attribute() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[io(encoding = "shift_jis")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        receiver.encoding,
+        Some(Encoding::Custom("shift_jis".into()))
+    );
+}
+
+#[test]
+fn unknown_word() {
+    let receiver = Encodings::from_derive_input(&parse_quote! {
+        #[io(encoding(latin1), encoding(ebcdic))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        receiver.encoding,
+        vec![Encoding::Latin1, Encoding::Custom("ebcdic".into())]
+    );
+}
+
+#[test]
+fn spanned_value() {
+    let receiver = Receiver::from_derive_input(&parse_quote! {
+        #[io(level = "extreme")]
+        struct Demo;
+    })
+    .unwrap();
+
+    match receiver.level {
+        Some(Level::Other(value)) => assert_eq!(*value, "extreme"),
+        other => panic!("Expected `Level::Other`, got {:?}", other),
+    }
+}
+
+#[test]
+fn non_string_is_still_an_error() {
+    let err = Receiver::from_derive_input(&parse_quote! {
+        #[io(encoding = 5)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+}
+
+#[test]
+fn other_not_suggested() {
+    #[derive(Debug, FromMeta)]
+    enum Mode {
+        Fast,
+        Careful,
+        #[darling(other)]
+        #[allow(dead_code)]
+        Custom(u8),
+    }
+
+    // Only words are passed to the catch-all variant, so a list still gets an error.
+    let err = Mode::from_list(&[parse_quote!(custm(x))])
+        .unwrap_err()
+        .to_string();
+    assert!(err.starts_with("Unknown field: `custm`"), "{}", err);
+    assert!(!err.contains("`custom`"), "{}", err);
+}
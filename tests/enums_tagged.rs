@@ -0,0 +1,123 @@
+//! Test that `#[darling(tag = "...")]` enums select their variant using a key in the same list
+//! as the variant's fields.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Remote {
+    url: String,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(tag = "kind", rename_all = "snake_case")]
+enum Source {
+    File {
+        path: String,
+        #[darling(default)]
+        watch: bool,
+    },
+    #[darling(alias = "http")]
+    Remote(Remote),
+    Stdin,
+    #[darling(skip)]
+    #[allow(dead_code)]
+    Internal,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(config))]
+struct Config {
+    source: Source,
+}
+
+fn parse(items: &[darling::ast::NestedMeta]) -> darling::Result<Source> {
+    Source::from_list(items)
+}
+
+#[test]
+fn struct_variant() {
+    let config = Config::from_derive_input(&parse_quote! {
+        #[config(source(kind = "file", path = "x"))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        config.source,
+        Source::File {
+            path: "x".into(),
+            watch: false
+        }
+    );
+}
+
+#[test]
+fn tag_position_does_not_matter() {
+    let source = parse(&[
+        parse_quote!(path = "x"),
+        parse_quote!(watch),
+        parse_quote!(kind = "file"),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        source,
+        Source::File {
+            path: "x".into(),
+            watch: true
+        }
+    );
+}
+
+#[test]
+fn newtype_variant() {
+    let source = parse(&[parse_quote!(kind = "http"), parse_quote!(url = "a")]).unwrap();
+    assert_eq!(source, Source::Remote(Remote { url: "a".into() }));
+}
+
+#[test]
+fn unit_variant() {
+    assert_eq!(
+        parse(&[parse_quote!(kind = "stdin")]).unwrap(),
+        Source::Stdin
+    );
+}
+
+#[test]
+fn unit_variant_rejects_fields() {
+    let err = parse(&[parse_quote!(kind = "stdin"), parse_quote!(path = "x")]).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown field: `path`");
+}
+
+#[test]
+fn missing_tag() {
+    let err = parse(&[parse_quote!(path = "x")]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `kind`");
+}
+
+#[test]
+fn duplicate_tag() {
+    let err = parse(&[parse_quote!(kind = "file"), parse_quote!(kind = "stdin")]).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `kind`");
+}
+
+#[test]
+fn unknown_tag() {
+    let err = parse(&[parse_quote!(kind = "fil")])
+        .unwrap_err()
+        .to_string();
+    assert!(err.starts_with("Unknown value: `fil`"), "{}", err);
+    assert!(err.contains("`file`"), "{}", err);
+    assert!(!err.contains("internal"), "{}", err);
+    assert!(err.ends_with("at kind"), "{}", err);
+}
+
+#[test]
+fn variant_errors() {
+    let err = parse(&[parse_quote!(kind = "file"), parse_quote!(paht = "x")]).unwrap_err();
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("Unknown field: `paht`"));
+    assert_eq!(errors[1], "Missing field `path`");
+}
@@ -0,0 +1,108 @@
+//! Test that `#[darling(untagged)]` enums try each variant in order against the whole input.
+
+use darling::{error::Warning, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(untagged)]
+enum Timeout {
+    Never,
+    Seconds(u64),
+    Detailed {
+        secs: u64,
+        #[darling(default)]
+        retry: u32,
+    },
+    #[darling(deprecated = "use `secs` instead")]
+    Millis {
+        ms: u64,
+    },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(client))]
+struct Client {
+    timeout: Option<Timeout>,
+    #[darling(warnings)]
+    warnings: Vec<Warning>,
+}
+
+fn parse(di: syn::DeriveInput) -> darling::Result<Option<Timeout>> {
+    Client::from_derive_input(&di).map(|client| client.timeout)
+}
+
+#[test]
+fn unit_variant() {
+    let timeout = parse(parse_quote! {
+        #[client(timeout)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(timeout, Some(Timeout::Never));
+}
+
+#[test]
+fn newtype_variant() {
+    let timeout = parse(parse_quote! {
+        #[client(timeout = 30)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(timeout, Some(Timeout::Seconds(30)));
+}
+
+#[test]
+fn struct_variant() {
+    let timeout = parse(parse_quote! {
+        #[client(timeout(secs = 30, retry = 2))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(timeout, Some(Timeout::Detailed { secs: 30, retry: 2 }));
+}
+
+#[test]
+fn later_struct_variant() {
+    let client = Client::from_derive_input(&parse_quote! {
+        #[client(timeout(ms = 500))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(client.timeout, Some(Timeout::Millis { ms: 500 }));
+    assert_eq!(client.warnings.len(), 1);
+    assert_eq!(
+        client.warnings[0].message(),
+        "use of deprecated variant `millis`: use `secs` instead"
+    );
+}
+
+#[test]
+fn no_match() {
+    let err = parse(parse_quote! {
+        #[client(timeout = "soon")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    // The summary comes first, followed by why each variant didn't match.
+    assert!(err.clone().flatten().into_iter().all(|e| e.has_span()));
+    let messages = err
+        .flatten()
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "Input did not match any variant of untagged enum `Timeout` at timeout",
+            "Unexpected type `string` at timeout/Never",
+            "Unknown value: `soon` at timeout/Seconds",
+            "Unexpected meta-item format `non-list` at timeout/Detailed",
+            "Unexpected meta-item format `non-list` at timeout/Millis",
+        ]
+    );
+}
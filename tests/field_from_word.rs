@@ -0,0 +1,92 @@
+//! Test that `from_word` and `from_expr` on a field override how that field is read.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::{parse_quote, Expr};
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Level {
+    Info,
+    Debug,
+}
+
+fn level_from_expr(expr: &Expr) -> darling::Result<Level> {
+    match expr {
+        Expr::Lit(_) => Level::from_expr(expr),
+        Expr::Path(path) if path.path.is_ident("verbose") => Ok(Level::Debug),
+        _ => Err(darling::Error::unexpected_expr_type(expr)),
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(logger))]
+struct Logger {
+    #[darling(from_word = || Ok(Level::Info), default = || Level::Debug)]
+    log: Level,
+    #[darling(from_expr = level_from_expr, default = || Level::Info)]
+    trace: Level,
+    #[darling(multiple, from_word = || Ok(String::from("*")))]
+    target: Vec<String>,
+}
+
+#[test]
+fn word_override() {
+    let logger = Logger::from_derive_input(&parse_quote! {
+        #[logger(log)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(logger.log, Level::Info);
+}
+
+#[test]
+fn other_forms_use_from_meta() {
+    let logger = Logger::from_derive_input(&parse_quote! {
+        #[logger(log = "debug")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(logger.log, Level::Debug);
+}
+
+#[test]
+fn expr_override() {
+    let logger = Logger::from_derive_input(&parse_quote! {
+        #[logger(trace = verbose)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(logger.trace, Level::Debug);
+
+    let logger = Logger::from_derive_input(&parse_quote! {
+        #[logger(trace = "info")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(logger.trace, Level::Info);
+}
+
+#[test]
+fn expr_override_error_is_located() {
+    let err = Logger::from_derive_input(&parse_quote! {
+        #[logger(trace = 1 + 2)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert!(err.to_string().ends_with("at trace"));
+}
+
+#[test]
+fn word_override_for_each_occurrence() {
+    let logger = Logger::from_derive_input(&parse_quote! {
+        #[logger(target, target = "app")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(logger.target, vec!["*", "app"]);
+}
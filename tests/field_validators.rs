@@ -0,0 +1,104 @@
+//! Test that `#[darling(validate(...))]` checks each value read for a field.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Server {
+    #[darling(validate(range(min = 1, max = 65535)))]
+    port: u32,
+    #[darling(validate(non_empty, len(max = 8)))]
+    name: String,
+    #[darling(default, validate(one_of("http", "https")))]
+    scheme: Option<String>,
+    #[darling(multiple, validate(range(min = 1)))]
+    retries: Vec<u8>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(service))]
+struct Service {
+    #[darling(validate(range(max = 1.0)))]
+    ratio: f64,
+    server: Server,
+}
+
+#[test]
+fn valid() {
+    let server = Server::from_list(&[
+        parse_quote!(port = 8080),
+        parse_quote!(name = "api"),
+        parse_quote!(scheme = "https"),
+        parse_quote!(retries = 3),
+    ])
+    .unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.name, "api");
+    assert_eq!(server.scheme.as_deref(), Some("https"));
+    assert_eq!(server.retries, vec![3]);
+
+    let service = Service::from_derive_input(&parse_quote! {
+        #[service(ratio = 0.5, server(port = 80, name = "web"))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(service.ratio, 0.5);
+    assert_eq!(service.server.port, 80);
+}
+
+#[test]
+fn failures_accumulate() {
+    let err = Server::from_list(&[
+        parse_quote!(port = 0),
+        parse_quote!(name = ""),
+        parse_quote!(scheme = "ftp"),
+        parse_quote!(retries = 0),
+    ])
+    .unwrap_err();
+
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "must be between 1 and 65535 at port",
+            "must not be empty at name",
+            "must be one of `http`, `https` at scheme",
+            "must be at least 1 at retries[0]",
+        ]
+    );
+}
+
+#[test]
+fn length_checks() {
+    let err = Server::from_list(&[parse_quote!(port = 1), parse_quote!(name = "")]).unwrap_err();
+    assert_eq!(err.to_string(), "must not be empty at name");
+
+    let err = Server::from_list(&[parse_quote!(port = 1), parse_quote!(name = "a-long-name")])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "length must be at most 8, but is 11 at name"
+    );
+}
+
+#[test]
+fn spanned_and_nested() {
+    let err = Service::from_derive_input(&parse_quote! {
+        #[service(ratio = 1.5, server(port = 70000, name = "api"))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert!(err.clone().into_iter().all(|e| e.has_span()));
+
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "must be at most 1 at ratio",
+            "must be between 1 and 65535 at server/port",
+        ]
+    );
+}
@@ -0,0 +1,158 @@
+//! Test that a `#[darling(flatten)]` enum field is selected by the variant key which appears
+//! at the parent level.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    Sqlite { path: String },
+    Postgres { url: String },
+    Memory,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(db))]
+struct Database {
+    name: String,
+    #[darling(flatten)]
+    mode: Mode,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(db))]
+struct OptionalMode {
+    name: String,
+    #[darling(flatten, default)]
+    mode: Option<Mode>,
+}
+
+#[derive(Debug, FromMeta)]
+struct Pool {
+    size: Option<u32>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(db))]
+struct PooledDatabase {
+    #[darling(flatten)]
+    mode: Mode,
+    #[darling(flatten)]
+    pool: Pool,
+}
+
+#[test]
+fn variant_selected_by_key() {
+    let db = Database::from_derive_input(&parse_quote! {
+        #[db(sqlite(path = "x"), name = "db")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(db.name, "db");
+    assert_eq!(db.mode, Mode::Sqlite { path: "x".into() });
+}
+
+#[test]
+fn unit_variant() {
+    let db = Database::from_derive_input(&parse_quote! {
+        #[db(name = "db", memory)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(db.mode, Mode::Memory);
+}
+
+#[test]
+fn no_variant() {
+    let err = Database::from_derive_input(&parse_quote! {
+        #[db(name = "db")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Exactly one of `sqlite`, `postgres`, `memory` must be set"
+    );
+    assert!(err.has_span());
+}
+
+#[test]
+fn optional_variant() {
+    let db = OptionalMode::from_derive_input(&parse_quote! {
+        #[db(name = "db")]
+        struct Demo;
+    })
+    .unwrap();
+    assert_eq!(db.name, "db");
+    assert_eq!(db.mode, None);
+
+    let db = OptionalMode::from_derive_input(&parse_quote! {
+        #[db(name = "db", memory)]
+        struct Demo;
+    })
+    .unwrap();
+    assert_eq!(db.mode, Some(Mode::Memory));
+
+    let err = OptionalMode::from_derive_input(&parse_quote! {
+        #[db(name = "db", memory, postgres(url = "x"))]
+        struct Demo;
+    })
+    .unwrap_err();
+    assert_eq!(err.len(), 2);
+}
+
+#[test]
+fn several_variants() {
+    let err = Database::from_derive_input(&parse_quote! {
+        #[db(name = "db", sqlite(path = "x"), memory)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 2);
+    assert!(err
+        .into_iter()
+        .all(|e| e.to_string() == "Only one of `sqlite`, `postgres`, `memory` may be set"));
+}
+
+#[test]
+fn unknown_key_suggests_variants_and_fields() {
+    let err = Database::from_derive_input(&parse_quote! {
+        #[db(name = "db", memory, sqlit(path = "x"))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `sqlit`. Did you mean `sqlite`?"
+    );
+}
+
+#[test]
+fn alongside_other_flattened_fields() {
+    let db = PooledDatabase::from_derive_input(&parse_quote! {
+        #[db(size = 4, postgres(url = "pg://"))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        db.mode,
+        Mode::Postgres {
+            url: "pg://".into()
+        }
+    );
+    assert_eq!(db.pool.size, Some(4));
+}
+
+#[test]
+fn nested_use_is_unchanged() {
+    assert_eq!(
+        Mode::from_list(&[parse_quote!(memory)]).unwrap(),
+        Mode::Memory
+    );
+}
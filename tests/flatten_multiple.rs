@@ -0,0 +1,182 @@
+//! Test that several `#[darling(flatten)]` fields each receive the keys their type accepts.
+
+use darling::{
+    util::{Flag, SpannedValue},
+    FromDeriveInput, FromMeta,
+};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct LoggingOpts {
+    level: Option<String>,
+    #[darling(alias = "log_file")]
+    file: Option<String>,
+}
+
+#[derive(Debug, FromMeta)]
+struct RetryOpts {
+    attempts: Option<u32>,
+    backoff: Flag,
+}
+
+#[derive(Debug, FromMeta)]
+struct TimeoutOpts {
+    timeout: Option<u32>,
+}
+
+#[derive(Debug, FromMeta)]
+struct NetworkOpts {
+    #[darling(flatten)]
+    retry: RetryOpts,
+    #[darling(flatten)]
+    timeout: TimeoutOpts,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(client))]
+struct Client {
+    name: String,
+    #[darling(flatten)]
+    logging: LoggingOpts,
+    #[darling(flatten)]
+    network: NetworkOpts,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(allow_unknown_fields)]
+struct Anything {
+    level: Option<String>,
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Overlapping {
+    #[darling(flatten)]
+    logging: LoggingOpts,
+    #[darling(flatten)]
+    other: LoggingOpts,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(allow_unknown_fields)]
+struct Lenient {
+    #[darling(flatten)]
+    retry: RetryOpts,
+    #[darling(flatten)]
+    timeout: TimeoutOpts,
+}
+
+#[derive(Debug, FromMeta)]
+struct Wrapped {
+    #[darling(flatten)]
+    retry: Box<RetryOpts>,
+    #[darling(flatten)]
+    timeout: SpannedValue<TimeoutOpts>,
+}
+
+#[derive(Debug, FromMeta)]
+struct WithOpen {
+    #[darling(flatten)]
+    retry: RetryOpts,
+    #[darling(flatten)]
+    rest: Anything,
+}
+
+#[test]
+fn dispatch_by_key() {
+    let client = Client::from_derive_input(&parse_quote! {
+        #[client(name = "a", level = "debug", attempts = 3, log_file = "x", timeout = 5, backoff)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(client.name, "a");
+    assert_eq!(client.logging.level.as_deref(), Some("debug"));
+    assert_eq!(client.logging.file.as_deref(), Some("x"));
+    assert_eq!(client.network.retry.attempts, Some(3));
+    assert!(client.network.retry.backoff.is_present());
+    assert_eq!(client.network.timeout.timeout, Some(5));
+}
+
+#[test]
+fn unknown_key_suggests_all_names() {
+    let err = Client::from_derive_input(&parse_quote! {
+        #[client(name = "a", atempts = 3)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+    let message = err.to_string();
+    assert!(
+        message.starts_with("Unknown field: `atempts`. Did you mean `attempts`?"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn field_names() {
+    assert_eq!(
+        NetworkOpts::field_names().unwrap(),
+        vec!["attempts", "backoff", "timeout"]
+    );
+    assert_eq!(
+        LoggingOpts::field_names().unwrap(),
+        vec!["level", "file", "log_file"]
+    );
+    assert_eq!(Anything::field_names(), None);
+}
+
+#[test]
+fn overlapping_keys() {
+    // Keys which only one of the types accepts aren't ambiguous.
+    Overlapping::from_list(&[]).unwrap();
+
+    let err = Overlapping::from_list(&[parse_quote!(level = "x"), parse_quote!(log_file = "y")])
+        .unwrap_err();
+    assert!(err.clone().into_iter().all(|e| e.has_span()));
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "Flattened fields `logging` and `other` both accept `level`",
+            "Flattened fields `logging` and `other` both accept `log_file`",
+        ]
+    );
+}
+
+#[test]
+fn open_receiver_gets_the_rest() {
+    let with_open = WithOpen::from_list(&[
+        parse_quote!(attempts = 1),
+        parse_quote!(level = "x"),
+        parse_quote!(whatever),
+    ])
+    .unwrap();
+
+    assert_eq!(with_open.retry.attempts, Some(1));
+    assert_eq!(with_open.rest.level.as_deref(), Some("x"));
+}
+
+#[test]
+fn allow_unknown_fields() {
+    let lenient =
+        Lenient::from_list(&[parse_quote!(attempts = 2), parse_quote!(whatever)]).unwrap();
+    assert_eq!(lenient.retry.attempts, Some(2));
+    assert_eq!(lenient.timeout.timeout, None);
+}
+
+#[test]
+fn wrapped_types() {
+    assert_eq!(<Box<RetryOpts>>::field_names(), RetryOpts::field_names());
+    assert_eq!(
+        <SpannedValue<TimeoutOpts>>::field_names(),
+        TimeoutOpts::field_names()
+    );
+
+    let wrapped =
+        Wrapped::from_list(&[parse_quote!(timeout = 5), parse_quote!(attempts = 2)]).unwrap();
+    assert_eq!(wrapped.retry.attempts, Some(2));
+    assert_eq!(wrapped.timeout.timeout, Some(5));
+}
@@ -0,0 +1,106 @@
+//! Test that `#[darling(flatten(prefix = "...", suffix = "..."))]` fields receive the keys
+//! which carry their affixes, with the affixes removed.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Endpoint {
+    timeout: u32,
+    #[darling(default)]
+    retries: u32,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(service))]
+struct Service {
+    name: String,
+    #[darling(flatten(prefix = "http_"))]
+    http: Endpoint,
+    #[darling(flatten(prefix = "grpc_"))]
+    grpc: Endpoint,
+}
+
+#[derive(Debug, FromMeta)]
+struct Limits {
+    #[darling(flatten(prefix = "read_", suffix = "_ms"))]
+    read: Endpoint,
+}
+
+#[test]
+fn two_instances_of_one_type() {
+    let service = Service::from_derive_input(&parse_quote! {
+        #[service(name = "api", http_timeout = 5, grpc_timeout = 10, grpc_retries = 2)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(service.name, "api");
+    assert_eq!(
+        service.http,
+        Endpoint {
+            timeout: 5,
+            retries: 0
+        }
+    );
+    assert_eq!(
+        service.grpc,
+        Endpoint {
+            timeout: 10,
+            retries: 2
+        }
+    );
+}
+
+#[test]
+fn prefix_and_suffix() {
+    let limits = Limits::from_list(&[parse_quote!(read_timeout_ms = 30)]).unwrap();
+    assert_eq!(limits.read.timeout, 30);
+}
+
+#[test]
+fn field_names_carry_affixes() {
+    assert_eq!(
+        Limits::field_names(),
+        Some(vec![
+            "read_timeout_ms".to_string(),
+            "read_retries_ms".to_string()
+        ])
+    );
+}
+
+#[test]
+fn unknown_field_suggests_prefixed_name() {
+    let err = Service::from_derive_input(&parse_quote! {
+        #[service(name = "api", http_timeout = 5, grpc_timeout = 10, http_timeuot = 3)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `http_timeuot`. Did you mean `http_timeout`?"
+    );
+}
+
+#[test]
+fn missing_field_uses_prefixed_name() {
+    let err = Service::from_derive_input(&parse_quote! {
+        #[service(name = "api", http_timeout = 5)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Missing field `grpc_timeout`");
+}
+
+#[test]
+fn invalid_value_located_at_prefixed_name() {
+    let err = Service::from_derive_input(&parse_quote! {
+        #[service(name = "api", http_timeout = "x", grpc_timeout = 10)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert!(err.to_string().ends_with("at http_timeout"));
+}
@@ -28,3 +28,105 @@ fn expand_many() {
         vec!["Hello".to_string(), "World".to_string()]
     );
 }
+
+#[derive(Debug, FromMeta)]
+struct Bounded {
+    #[darling(multiple(min = 1, max = 2))]
+    route: Vec<String>,
+    #[darling(multiple(min = 2), default = "default_tags")]
+    tag: Vec<String>,
+}
+
+fn default_tags() -> Vec<String> {
+    vec!["a".into(), "b".into()]
+}
+
+#[derive(Debug, FromMeta)]
+struct Sets {
+    #[darling(multiple)]
+    derive: std::collections::BTreeSet<String>,
+    #[darling(multiple)]
+    feature: std::collections::HashSet<String>,
+    #[darling(multiple)]
+    other: std::collections::VecDeque<u8>,
+}
+
+#[test]
+fn within_bounds() {
+    let bounded =
+        Bounded::from_list(&[parse_quote!(route = "a"), parse_quote!(route = "b")]).unwrap();
+    assert_eq!(bounded.route, vec!["a", "b"]);
+    assert_eq!(bounded.tag, vec!["a", "b"]);
+}
+
+#[test]
+fn too_few() {
+    let err = Bounded::from_list(&[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Too few items: Expected at least 1 at route"
+    );
+}
+
+#[test]
+fn too_few_with_default() {
+    let err =
+        Bounded::from_list(&[parse_quote!(route = "a"), parse_quote!(tag = "x")]).unwrap_err();
+    assert_eq!(err.to_string(), "Too few items: Expected at least 2 at tag");
+}
+
+#[test]
+fn too_many() {
+    let err = Bounded::from_list(&[
+        parse_quote!(route = "a"),
+        parse_quote!(route = "b"),
+        parse_quote!(route = "c"),
+    ])
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Too many items: Expected no more than 2 at route[2]"
+    );
+    assert_eq!(err.len(), 1);
+}
+
+#[test]
+fn sets() {
+    let sets = Sets::from_list(&[
+        parse_quote!(derive = "Debug"),
+        parse_quote!(feature = "x"),
+        parse_quote!(derive = "Clone"),
+        parse_quote!(other = 2),
+        parse_quote!(other = 1),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        sets.derive.into_iter().collect::<Vec<_>>(),
+        vec!["Clone", "Debug"]
+    );
+    assert!(sets.feature.contains("x"));
+    assert_eq!(sets.other, vec![2, 1]);
+}
+
+#[test]
+fn duplicate_values() {
+    let err = Sets::from_list(&[
+        parse_quote!(derive = "Debug"),
+        parse_quote!(feature = "x"),
+        parse_quote!(derive = "Debug"),
+        parse_quote!(feature = "x"),
+        parse_quote!(other = 1),
+        parse_quote!(other = 1),
+    ])
+    .unwrap_err();
+
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "Duplicate value at derive[1]",
+            "Duplicate value at feature[1]"
+        ]
+    );
+}
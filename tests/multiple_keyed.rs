@@ -0,0 +1,70 @@
+//! Test that `#[darling(multiple, key = "...")]` collects repeated items into a map.
+
+use std::collections::{BTreeMap, HashMap};
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Route {
+    name: String,
+    path: String,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(router))]
+struct Router {
+    #[darling(multiple, key = "name")]
+    route: BTreeMap<String, Route>,
+    #[darling(multiple, key = "name", rename = "fallback")]
+    fallbacks: HashMap<String, Route>,
+}
+
+#[test]
+fn collect_by_key() {
+    let router = Router::from_derive_input(&parse_quote! {
+        #[router(route(name = "b", path = "/b"))]
+        #[router(route(name = "a", path = "/a"), fallback(name = "x", path = "/"))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(router.route.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(router.route["b"].path, "/b");
+    assert_eq!(router.fallbacks["x"].path, "/");
+}
+
+#[test]
+fn empty() {
+    let router = Router::from_derive_input(&parse_quote! {
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(router.route.is_empty());
+}
+
+#[test]
+fn duplicate_keys() {
+    let err = Router::from_derive_input(&parse_quote! {
+        #[router(route(name = "a", path = "/1"))]
+        #[router(route(name = "a", path = "/2"), route(name = "a", path = "/3"))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    // The first occurrence is reported once, followed by each repeat.
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors, vec!["Each `route` must have a unique `name`"; 3]);
+}
+
+#[test]
+fn item_errors_have_location() {
+    let err = Router::from_derive_input(&parse_quote! {
+        #[router(route(name = "a", path = "/1"), route(name = "b"))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Missing field `path` at route[1]");
+}
@@ -0,0 +1,196 @@
+//! Test that `#[darling(on_duplicate = "...")]` controls how repeated keys are handled.
+
+use darling::{util::PathList, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta)]
+struct Tls {
+    cert: Option<String>,
+    key: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Route {
+    path: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta)]
+struct Http {
+    port: Option<u16>,
+    host: Option<String>,
+    tls: Option<Tls>,
+    #[darling(multiple)]
+    route: Vec<Route>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    #[darling(on_duplicate = "merge")]
+    http: Option<Http>,
+    #[darling(on_duplicate = "merge", default)]
+    derive: PathList,
+    #[darling(on_duplicate = "first")]
+    name: Option<String>,
+    #[darling(on_duplicate = "last")]
+    workers: Option<u32>,
+    #[allow(dead_code)]
+    level: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server), on_duplicate = "last")]
+struct Overrides {
+    name: Option<String>,
+    #[darling(on_duplicate = "error")]
+    #[allow(dead_code)]
+    level: Option<String>,
+}
+
+#[test]
+fn merge_across_attributes() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(http(port = 1))]
+        #[server(http(host = "x"))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        server.http,
+        Some(Http {
+            port: Some(1),
+            host: Some("x".into()),
+            tls: None,
+            route: vec![],
+        })
+    );
+}
+
+#[test]
+fn merge_appends_lists() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(derive(Debug), derive(Clone))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.derive.to_strings(), vec!["Debug", "Clone"]);
+}
+
+#[test]
+fn merge_is_deep() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(http(tls(cert = "a")))]
+        #[server(http(tls(key = "b")))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        server.http.unwrap().tls,
+        Some(Tls {
+            cert: Some("a".into()),
+            key: Some("b".into()),
+        })
+    );
+}
+
+#[test]
+fn merge_appends_nested_multiple_fields() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(http(route(path = "/a"), tls(cert = "a")))]
+        #[server(http(route(path = "/b"), tls(key = "b")))]
+        struct Demo;
+    })
+    .unwrap();
+
+    let http = server.http.unwrap();
+    assert_eq!(
+        http.route,
+        vec![Route { path: "/a".into() }, Route { path: "/b".into() }]
+    );
+    assert_eq!(
+        http.tls,
+        Some(Tls {
+            cert: Some("a".into()),
+            key: Some("b".into()),
+        })
+    );
+}
+
+#[test]
+fn merge_reports_deep_duplicates() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(http(tls(cert = "a")))]
+        #[server(http(tls(cert = "b")))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Duplicate field `cert` at http/tls");
+}
+
+#[test]
+fn merge_reports_nested_duplicates() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(http(port = 1))]
+        #[server(http(port = 2))]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Duplicate field `port` at http");
+}
+
+#[test]
+fn merge_rejects_non_lists() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(http(port = 1), http = "x")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Only list values can be merged");
+}
+
+#[test]
+fn first_and_last() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(name = "a", workers = 1)]
+        #[server(name = "b", workers = 2)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.name.as_deref(), Some("a"));
+    assert_eq!(server.workers, Some(2));
+}
+
+#[test]
+fn error_by_default() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(level = "a", level = "b")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Duplicate field `level`");
+}
+
+#[test]
+fn container_policy_with_field_override() {
+    let overrides = Overrides::from_derive_input(&parse_quote! {
+        #[server(name = "a", name = "b")]
+        struct Demo;
+    })
+    .unwrap();
+    assert_eq!(overrides.name.as_deref(), Some("b"));
+
+    let err = Overrides::from_derive_input(&parse_quote! {
+        #[server(level = "a", level = "b")]
+        struct Demo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `level`");
+}
@@ -0,0 +1,147 @@
+//! Test that `#[darling(positional)]` fields can be populated by leading literals and bare paths.
+
+use darling::{util::Flag, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Route {
+    #[darling(positional)]
+    path: String,
+    #[darling(positional)]
+    method: Option<syn::Ident>,
+    timeout: Option<u32>,
+    cached: Flag,
+}
+
+#[derive(Debug, FromMeta)]
+struct Command {
+    #[darling(positional)]
+    name: String,
+    #[darling(rest)]
+    args: Vec<darling::ast::NestedMeta>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(route))]
+struct Handler {
+    #[darling(positional)]
+    path: String,
+    #[darling(default)]
+    method: Option<String>,
+}
+
+fn parse(items: &[darling::ast::NestedMeta]) -> darling::Result<Route> {
+    Route::from_list(items)
+}
+
+#[test]
+fn literal_then_named() {
+    let route = parse(&[parse_quote!("/users"), parse_quote!(method = GET)]).unwrap();
+    assert_eq!(route.path, "/users");
+    assert_eq!(route.method.unwrap(), "GET");
+}
+
+#[test]
+fn bare_path() {
+    let route = parse(&[
+        parse_quote!("/users"),
+        parse_quote!(POST),
+        parse_quote!(timeout = 5),
+    ])
+    .unwrap();
+
+    assert_eq!(route.method.unwrap(), "POST");
+    assert_eq!(route.timeout, Some(5));
+}
+
+#[test]
+fn optional_positional_omitted() {
+    let route = parse(&[parse_quote!("/users")]).unwrap();
+    assert!(route.method.is_none());
+}
+
+#[test]
+fn field_name_is_not_positional() {
+    let route = parse(&[parse_quote!("/users"), parse_quote!(cached)]).unwrap();
+    assert!(route.method.is_none());
+    assert!(route.cached.is_present());
+}
+
+#[test]
+fn positional_by_name() {
+    let route = parse(&[parse_quote!(path = "/users")]).unwrap();
+    assert_eq!(route.path, "/users");
+}
+
+#[test]
+fn positional_and_named_duplicate() {
+    let err = parse(&[parse_quote!("/users"), parse_quote!(path = "/other")]).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `path`");
+}
+
+#[test]
+fn too_many() {
+    let err = parse(&[
+        parse_quote!("/users"),
+        parse_quote!(GET),
+        parse_quote!("extra"),
+    ])
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Too many positional arguments: Expected no more than 2"
+    );
+}
+
+#[test]
+fn excess_literals_go_to_rest() {
+    let command = Command::from_list(&[
+        parse_quote!("build"),
+        parse_quote!("--release"),
+        parse_quote!(verbose),
+        parse_quote!(5),
+    ])
+    .unwrap();
+
+    assert_eq!(command.name, "build");
+    assert_eq!(command.args.len(), 3);
+}
+
+#[test]
+fn too_few() {
+    let err = parse(&[parse_quote!(method = GET)]).unwrap_err();
+    assert_eq!(err.to_string(), "Missing positional argument `path`");
+}
+
+#[test]
+fn after_named() {
+    let err = parse(&[parse_quote!(timeout = 5), parse_quote!("/users")]).unwrap_err();
+    let errors = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "Positional arguments must come before named arguments",
+            "Missing positional argument `path`",
+        ]
+    );
+}
+
+#[test]
+fn bad_value_has_location() {
+    let err = parse(&[parse_quote!(5)]).unwrap_err();
+    assert_eq!(err.len(), 1);
+    assert!(err.to_string().contains("at path"));
+}
+
+#[test]
+fn derive_input() {
+    let handler = Handler::from_derive_input(&parse_quote! {
+        #[route("/users", method = "GET")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(handler.path, "/users");
+    assert_eq!(handler.method.as_deref(), Some("GET"));
+}
@@ -0,0 +1,56 @@
+//! Test that the fields of enum variants can be renamed separately from the variants, with
+//! `rename_all_fields` on the enum or `rename_all` on a variant.
+
+use darling::FromMeta;
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "PascalCase", rename_all_fields = "snake_case")]
+enum Source {
+    LocalFile {
+        file_path: String,
+    },
+    #[darling(rename_all = "camelCase")]
+    RemoteUrl {
+        base_url: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "PascalCase")]
+enum Legacy {
+    LocalFile { file_path: String },
+}
+
+#[test]
+fn variant_and_field_rules_differ() {
+    let source = Source::from_list(&[parse_quote!(LocalFile(file_path = "a.txt"))]).unwrap();
+    assert_eq!(
+        source,
+        Source::LocalFile {
+            file_path: "a.txt".into()
+        }
+    );
+}
+
+#[test]
+fn variant_rule_overrides_enum() {
+    let source = Source::from_list(&[parse_quote!(RemoteUrl(baseUrl = "x"))]).unwrap();
+    assert_eq!(
+        source,
+        Source::RemoteUrl {
+            base_url: "x".into()
+        }
+    );
+}
+
+#[test]
+fn fields_follow_rename_all_by_default() {
+    let legacy = Legacy::from_list(&[parse_quote!(LocalFile(FilePath = "a.txt"))]).unwrap();
+    assert_eq!(
+        legacy,
+        Legacy::LocalFile {
+            file_path: "a.txt".into()
+        }
+    );
+}
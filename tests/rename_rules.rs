@@ -0,0 +1,112 @@
+//! Test the rules `rename_all` supports beyond `ident_case`'s: `SCREAMING-KEBAB-CASE`, removing
+//! a prefix or suffix, and a function which computes each name.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+fn joined(name: &str) -> String {
+    name.replace('_', "")
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "SCREAMING-KEBAB-CASE")]
+enum Level {
+    LowPriority,
+    HighPriority,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(rename_all(case = "camelCase", strip_prefix = "opt_"))]
+struct Options {
+    opt_max_size: u32,
+    #[darling(default)]
+    verbose: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all(strip_suffix = "Mode"))]
+enum Mode {
+    ReadMode,
+    WriteMode,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server), rename_all = joined)]
+struct Server {
+    #[darling(conflicts_with = "tls_port")]
+    http_port: Option<u16>,
+    tls_port: Option<u16>,
+    #[darling(rename = "name")]
+    server_name: String,
+    #[darling(default, multiple)]
+    log_target: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = str::to_uppercase)]
+enum Protocol {
+    Http,
+    Custom { port_number: u16 },
+}
+
+#[test]
+fn screaming_kebab_case() {
+    assert_eq!(
+        Level::from_string("HIGH-PRIORITY").unwrap(),
+        Level::HighPriority
+    );
+    assert!(Level::from_string("high_priority").is_err());
+}
+
+#[test]
+fn strip_affixes() {
+    let options = Options::from_list(&[parse_quote!(maxSize = 3), parse_quote!(verbose)]).unwrap();
+    assert_eq!(options.opt_max_size, 3);
+    assert!(options.verbose);
+
+    // Stripping an affix keeps the enum's default case rule.
+    assert_eq!(Mode::from_string("write").unwrap(), Mode::WriteMode);
+}
+
+#[test]
+fn function() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(httpport = 80, name = "api", logtarget = "a", logtarget = "b")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.http_port, Some(80));
+    assert_eq!(server.tls_port, None);
+    assert_eq!(server.server_name, "api");
+    assert_eq!(server.log_target, vec!["a", "b"]);
+}
+
+#[test]
+fn function_errors_use_computed_names() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(httpport = 80, tlsport = 443, log_target = "a")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    let messages: Vec<_> = err.flatten().into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Unknown field: `log_target`. Did you mean `logtarget`?",
+            "Missing field `name`",
+            "`httpport` cannot be used together with `tlsport`",
+            "`tlsport` cannot be used together with `httpport`",
+        ]
+    );
+}
+
+#[test]
+fn function_on_enum() {
+    assert_eq!(Protocol::from_string("HTTP").unwrap(), Protocol::Http);
+    assert_eq!(
+        Protocol::from_list(&[parse_quote!(CUSTOM(PORT_NUMBER = 8080))]).unwrap(),
+        Protocol::Custom { port_number: 8080 }
+    );
+}
@@ -0,0 +1,115 @@
+//! Test that a `#[darling(rest)]` field collects every item that no other field claimed.
+
+use std::collections::BTreeMap;
+
+use darling::{ast::NestedMeta, FromDeriveInput, FromMeta};
+use quote::ToTokens;
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Forwarded {
+    name: String,
+    #[darling(rest)]
+    passthrough: Vec<NestedMeta>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(wrap))]
+struct Keyed {
+    #[darling(default)]
+    skip: bool,
+    #[darling(rest)]
+    others: BTreeMap<String, syn::Meta>,
+}
+
+#[derive(Debug, FromMeta)]
+enum Target {
+    Field {
+        ty: String,
+        #[darling(rest)]
+        extra: Vec<NestedMeta>,
+    },
+}
+
+fn tokens(items: &[NestedMeta]) -> Vec<String> {
+    items
+        .iter()
+        .map(|item| item.to_token_stream().to_string())
+        .collect()
+}
+
+#[test]
+fn unmatched_items_in_order() {
+    let forwarded = Forwarded::from_list(&[
+        parse_quote!(rename = "x"),
+        parse_quote!(name = "a"),
+        parse_quote!(serde(default)),
+        parse_quote!("lit"),
+    ])
+    .unwrap();
+
+    assert_eq!(forwarded.name, "a");
+    assert_eq!(
+        tokens(&forwarded.passthrough),
+        vec!["rename = \"x\"", "serde (default)", "\"lit\""]
+    );
+}
+
+#[test]
+fn empty_when_all_claimed() {
+    let forwarded = Forwarded::from_list(&[parse_quote!(name = "a")]).unwrap();
+    assert!(forwarded.passthrough.is_empty());
+}
+
+#[test]
+fn known_fields_still_checked() {
+    let err = Forwarded::from_list(&[parse_quote!(name = 1), parse_quote!(other)]).unwrap_err();
+    assert_eq!(err.len(), 1);
+    assert!(err.to_string().contains("at name"));
+}
+
+#[test]
+fn map_by_key() {
+    let keyed = Keyed::from_derive_input(&parse_quote! {
+        #[wrap(skip, doc = "x", serde::rename = "y")]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert!(keyed.skip);
+    assert_eq!(
+        keyed.others.keys().collect::<Vec<_>>(),
+        vec!["doc", "serde::rename"]
+    );
+}
+
+#[test]
+fn map_rejects_duplicate_keys() {
+    let err = Keyed::from_derive_input(&parse_quote! {
+        #[wrap(doc = "x", doc = "y")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Duplicate field `doc`");
+}
+
+#[test]
+fn map_rejects_literals() {
+    let err = Keyed::from_derive_input(&parse_quote! {
+        #[wrap("x")]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "Unexpected meta-item format `literal`");
+}
+
+#[test]
+fn enum_variant() {
+    let Target::Field { ty, extra } =
+        Target::from_list(&[parse_quote!(field(ty = "u8", default))]).unwrap();
+
+    assert_eq!(ty, "u8");
+    assert_eq!(tokens(&extra), vec!["default"]);
+}
@@ -0,0 +1,85 @@
+//! Test that tuple structs and tuple variants with several fields read their values in order.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Point(u32, u32);
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Range(u32, Option<u32>, #[darling(default)] bool);
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Shape {
+    Line(Point, Point),
+    Circle(u32),
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(canvas))]
+struct Canvas {
+    origin: Point,
+    #[darling(default)]
+    shape: Option<Shape>,
+}
+
+#[test]
+fn literals() {
+    let point = Point::from_list(&[parse_quote!(1), parse_quote!("2")]).unwrap();
+    assert_eq!(point, Point(1, 2));
+}
+
+#[test]
+fn optional_trailing_fields() {
+    assert_eq!(
+        Range::from_list(&[parse_quote!(1)]).unwrap(),
+        Range(1, None, false)
+    );
+    assert_eq!(
+        Range::from_list(&[parse_quote!(1), parse_quote!(5), parse_quote!(true)]).unwrap(),
+        Range(1, Some(5), true)
+    );
+}
+
+#[test]
+fn nested() {
+    let canvas = Canvas::from_derive_input(&parse_quote! {
+        #[canvas(origin(0, 0), shape(line(point(1, 2), point(3, 4))))]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(canvas.origin, Point(0, 0));
+    assert_eq!(canvas.shape, Some(Shape::Line(Point(1, 2), Point(3, 4))));
+}
+
+#[test]
+fn too_few() {
+    let err = Point::from_list(&[parse_quote!(1)]).unwrap_err();
+    assert_eq!(err.to_string(), "Too few items: Expected at least 2");
+}
+
+#[test]
+fn too_many() {
+    let err = Point::from_list(&[parse_quote!(1), parse_quote!(2), parse_quote!(3)]).unwrap_err();
+    assert_eq!(err.to_string(), "Too many items: Expected no more than 2");
+}
+
+#[test]
+fn errors_at_index() {
+    let err = Point::from_list(&[parse_quote!("a"), parse_quote!("b")]).unwrap_err();
+    assert_eq!(err.len(), 2);
+
+    let locations = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert!(locations[0].ends_with("at 0"), "{}", locations[0]);
+    assert!(locations[1].ends_with("at 1"), "{}", locations[1]);
+}
+
+#[test]
+fn variant_errors_include_name() {
+    let err = Shape::from_list(&[parse_quote!(line(point(1, 2)))]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Too few items: Expected at least 2 at line"
+    );
+}
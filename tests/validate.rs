@@ -0,0 +1,95 @@
+//! Test that `#[darling(validate = ...)]` reports every problem it finds, and that errors
+//! located at a field point at that field's key.
+
+use darling::{error::Accumulator, Error, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server), validate = Self::validate)]
+struct Server {
+    #[darling(default)]
+    min_port: u16,
+    #[darling(default)]
+    max_port: u16,
+    #[darling(default)]
+    workers: usize,
+}
+
+impl Server {
+    fn validate(&self, errors: &mut Accumulator) {
+        if self.min_port > self.max_port {
+            errors.push(Error::custom("must not be greater than `max_port`").at("min_port"));
+        }
+
+        if self.workers == 0 {
+            errors.push(Error::custom("must be at least 1").at("workers"));
+        }
+    }
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(validate = |this: &Self, errors: &mut Accumulator| if this.name.is_empty() {
+    errors.push(Error::custom("must not be empty").at("name"));
+}, map = Named::shout)]
+struct Named {
+    name: String,
+}
+
+impl Named {
+    fn shout(self) -> Self {
+        Named {
+            name: self.name.to_uppercase(),
+        }
+    }
+}
+
+#[test]
+fn valid() {
+    let server = Server::from_derive_input(&parse_quote! {
+        #[server(min_port = 80, max_port = 90, workers = 4)]
+        struct Demo;
+    })
+    .unwrap();
+
+    assert_eq!(server.max_port, 90);
+}
+
+#[test]
+fn reports_every_error() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(min_port = 100, max_port = 90)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "must not be greater than `max_port` at min_port",
+            "must be at least 1 at workers",
+        ]
+    );
+}
+
+#[test]
+fn errors_point_at_keys() {
+    let err = Server::from_derive_input(&parse_quote! {
+        #[server(min_port = 100, max_port = 90)]
+        struct Demo;
+    })
+    .unwrap_err();
+
+    // `workers` wasn't in the input, so there is no key to point at.
+    let spanned: Vec<_> = err.into_iter().map(|e| e.has_span()).collect();
+    assert_eq!(spanned, vec![true, false]);
+}
+
+#[test]
+fn runs_before_map() {
+    let named = Named::from_list(&[parse_quote!(name = "api")]).unwrap();
+    assert_eq!(named.name, "API");
+
+    let err = Named::from_list(&[parse_quote!(name = "")]).unwrap_err();
+    assert_eq!(err.to_string(), "must not be empty at name");
+}